@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::fs;
+use std::time::Instant;
+use colored::*;
+use regex::Regex;
+use crate::input::Input;
+use crate::make_day;
+
+lazy_static! {
+    static ref RESULT_REGEX : Regex = Regex::new(r#"\{"day": (\d+), "stage": "(\w+)", "min_ms": ([\d.]+), "median_ms": ([\d.]+), "mean_ms": ([\d.]+)\}"#).unwrap();
+}
+
+const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+// Changes below this are timer noise and never count as regression
+const NOISE_MS: f64 = 0.05;
+
+struct Timing {
+    day: u8,
+    stage: &'static str,
+    min_ms: f64,
+    median_ms: f64,
+    mean_ms: f64,
+}
+
+impl Timing {
+    fn make(day: u8, stage: &'static str, samples: &mut Vec<f64>) -> Self {
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mid = samples.len() / 2;
+        let median = if samples.len() % 2 == 0 { (samples[mid - 1] + samples[mid]) / 2.0 } else { samples[mid] };
+        return Timing {
+            day: day,
+            stage: stage,
+            min_ms: samples[0],
+            median_ms: median,
+            mean_ms: samples.iter().sum::<f64>() / samples.len() as f64
+        };
+    }
+
+    fn to_json(self: &Self) -> String {
+        return format!("{{\"day\": {}, \"stage\": \"{}\", \"min_ms\": {:.4}, \"median_ms\": {:.4}, \"mean_ms\": {:.4}}}",
+            self.day, self.stage, self.min_ms, self.median_ms, self.mean_ms);
+    }
+}
+
+pub struct BenchOptions {
+    pub days: Vec<u8>,
+    pub runs: usize,
+    pub output: String,
+    pub threshold: f64,
+    pub accept: bool,
+}
+
+/**
+ * Read the median timings of a previous run, keyed by day and stage. A missing file has no timings.
+ */
+fn read_previous(path: &str) -> Result<HashMap<(u8, String), f64>, String> {
+    let text = fs::read_to_string(path).unwrap_or_default();
+    let mut previous = HashMap::new();
+    for caps in RESULT_REGEX.captures_iter(&text) {
+        let day = caps[1].parse::<u8>().map_err(|_e| format!("Invalid day {} in {}", &caps[1], path))?;
+        let median = caps[4].parse::<f64>().map_err(|_e| format!("Invalid median {} of day {} in {}", &caps[4], day, path))?;
+        previous.insert((day, caps[2].to_string()), median);
+    }
+    return Ok(previous);
+}
+
+/**
+ * Solve every day the given number of times, all samples of a day use a freshly parsed puzzle.
+ * The extra benchmarks of the day are run just as often.
+ */
+fn bench_day(day: u8, runs: usize) -> Result<Vec<Timing>, String> {
+    let path = make_day(day).unwrap().input_path();
+    let input = Input::from_path(path).map_err(|err| format!("{}: {}", path, err))?;
+
+    let mut samples: Vec<Vec<f64>> = vec![Vec::with_capacity(runs); STAGES.len()];
+    for _ in 0..runs {
+        let mut puzzle = make_day(day).unwrap();
+        let start = Instant::now();
+        puzzle.parse(&input).map_err(|err| err.to_string())?;
+        samples[0].push(start.elapsed().as_secs_f64() * 1000.0);
+
+        let start = Instant::now();
+        puzzle.part1();
+        samples[1].push(start.elapsed().as_secs_f64() * 1000.0);
+
+        let start = Instant::now();
+        puzzle.part2();
+        samples[2].push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+
+    let mut timings: Vec<Timing> = samples.iter_mut().enumerate().map(|(i, stage)| Timing::make(day, STAGES[i], stage)).collect();
+    for benchmark in make_day(day).unwrap().benchmarks() {
+        let mut samples = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let start = Instant::now();
+            (benchmark.run)();
+            samples.push(start.elapsed().as_secs_f64() * 1000.0);
+        }
+        timings.push(Timing::make(day, benchmark.name, &mut samples));
+    }
+    return Ok(timings);
+}
+
+/**
+ * Benchmark the days, compare the medians with the previous results and store the new ones.
+ * Results with a regression are only stored if they are accepted, so they don't become the new baseline.
+ * Returns false if a day failed or got slower than the threshold allows.
+ */
+pub fn bench(options: &BenchOptions) -> bool {
+    let previous = match read_previous(&options.output) {
+        Ok(previous) => previous,
+        Err(err) => {
+            println!("Error while reading previous results! {}", err);
+            return false;
+        }
+    };
+    let mut timings = Vec::new();
+    let mut success = true;
+    let mut regressed = false;
+
+    println!("Day Stage            min     median       mean   change");
+    for day in &options.days {
+        let results = match bench_day(*day, options.runs) {
+            Ok(results) => results,
+            Err(err) => {
+                println!("{:>3} Error while solving! {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+
+        for timing in results {
+            let change = match previous.get(&(timing.day, timing.stage.to_string())) {
+                Some(old) if *old > 0.0 => {
+                    let percent = (timing.median_ms - old) / old * 100.0;
+                    let text = format!("{:+7.1}%", percent);
+                    if (timing.median_ms - old).abs() < NOISE_MS {
+                        text.normal()
+                    }
+                    else if percent > options.threshold {
+                        regressed = true;
+                        text.red().bold()
+                    }
+                    else if percent < -options.threshold {
+                        text.green()
+                    }
+                    else {
+                        text.normal()
+                    }
+                },
+                _ => "     new".normal()
+            };
+
+            println!("{:>3} {:<10} {:>8.3}ms {:>8.3}ms {:>8.3}ms {}",
+                timing.day, timing.stage, timing.min_ms, timing.median_ms, timing.mean_ms, change);
+            timings.push(timing);
+        }
+    }
+
+    if regressed && !options.accept {
+        println!("Results not stored in {} because of the regressions, use --accept to store them anyway", options.output);
+        return false;
+    }
+
+    let results: Vec<String> = timings.iter().map(|timing| format!("    {}", timing.to_json())).collect();
+    let json = format!("{{\n  \"runs\": {},\n  \"results\": [\n{}\n  ]\n}}\n", options.runs, results.join(",\n"));
+    if let Err(err) = fs::write(&options.output, json) {
+        println!("Error while writing results to {}! {}", options.output, err);
+        return false;
+    }
+    return success && !regressed;
+}
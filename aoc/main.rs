@@ -0,0 +1,620 @@
+#[macro_use]
+extern crate lazy_static;
+
+#[path = "../common/title.rs"]
+mod title;
+#[path = "../common/day.rs"]
+mod day;
+#[path = "../common/answer.rs"]
+mod answer;
+#[path = "../common/input.rs"]
+mod input;
+#[path = "../common/intcomputer.rs"]
+mod intcomputer;
+#[path = "../common/intasync.rs"]
+mod intasync;
+#[path = "../common/symbolic.rs"]
+mod symbolic;
+#[path = "../common/grid.rs"]
+mod grid;
+#[path = "../common/goalseek.rs"]
+mod goalseek;
+#[path = "../common/search.rs"]
+mod search;
+#[path = "../common/image.rs"]
+mod image;
+#[path = "../common/recorder.rs"]
+mod recorder;
+#[path = "../common/report.rs"]
+mod report;
+#[path = "../common/ocr.rs"]
+mod ocr;
+#[path = "../common/loader.rs"]
+mod loader;
+
+#[path = "../day_01/mod.rs"]
+mod day01;
+#[path = "../day_02/mod.rs"]
+mod day02;
+#[path = "../day_03/mod.rs"]
+mod day03;
+#[path = "../day_04/mod.rs"]
+mod day04;
+#[path = "../day_05/mod.rs"]
+mod day05;
+#[path = "../day_06/mod.rs"]
+mod day06;
+#[path = "../day_07/mod.rs"]
+mod day07;
+#[path = "../day_08/mod.rs"]
+mod day08;
+#[path = "../day_09/mod.rs"]
+mod day09;
+#[path = "../day_10/mod.rs"]
+mod day10;
+#[path = "../day_11/mod.rs"]
+mod day11;
+#[path = "../day_12/mod.rs"]
+mod day12;
+#[path = "../day_13/mod.rs"]
+mod day13;
+#[path = "../day_14/mod.rs"]
+mod day14;
+
+mod verify;
+mod bench;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process;
+use std::time::Instant;
+use day::Day;
+use input::Input;
+
+const USAGE: &str = "Usage: aoc [--color <auto|always|never>] [--quiet] <command>
+       aoc run <day|all> [--part <1|2>] [--input <file|->] [--stream] [--time] [--format <text|json>]
+                         [--render <file.pbm|file.ppm|file.svg>] [--record <dir|file.cast> [--every <n>]]
+                         [--scale <n>] [--palette <rrggbb,...>] [--report] [--csv <file>]
+       aoc verify [day...|all] [--answers <file>]
+       aoc bench [day...|all] [--runs <n>] [--output <file>] [--threshold <percent>] [--accept]
+       aoc tool <day> <command> [args...] [--input <file|->]
+       aoc list";
+
+#[derive(PartialEq)]
+enum Format {
+    Text,
+    Json
+}
+
+struct Options {
+    days: Vec<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    stream: bool,
+    time: bool,
+    format: Format,
+    quiet: bool,
+    render: Option<String>,
+    record: Option<String>,
+    every: usize,
+    scale: usize,
+    palette: Vec<image::Color>,
+    report: bool,
+    csv: Option<String>,
+}
+
+fn make_day(day: u8) -> Option<Box<dyn Day>> {
+    let puzzle: Box<dyn Day> = match day {
+        1 => Box::new(day01::Puzzle::default()),
+        2 => Box::new(day02::Puzzle::default()),
+        3 => Box::new(day03::Puzzle::default()),
+        4 => Box::new(day04::Puzzle::default()),
+        5 => Box::new(day05::Puzzle::default()),
+        6 => Box::new(day06::Puzzle::default()),
+        7 => Box::new(day07::Puzzle::default()),
+        8 => Box::new(day08::Puzzle::default()),
+        9 => Box::new(day09::Puzzle::default()),
+        10 => Box::new(day10::Puzzle::default()),
+        11 => Box::new(day11::Puzzle::default()),
+        12 => Box::new(day12::Puzzle::default()),
+        13 => Box::new(day13::Puzzle::default()),
+        14 => Box::new(day14::Puzzle::default()),
+        _ => return Option::None
+    };
+    return Option::from(puzzle);
+}
+
+fn available_days() -> Vec<u8> {
+    return (1..=25).filter(|day| make_day(*day).is_some()).collect();
+}
+
+fn parse_run_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        days: Vec::new(),
+        part: Option::None,
+        input: Option::None,
+        stream: false,
+        time: false,
+        format: Format::Text,
+        quiet: false,
+        render: Option::None,
+        record: Option::None,
+        every: 1,
+        scale: 1,
+        palette: Vec::new(),
+        report: false,
+        csv: Option::None
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--part" => {
+                i += 1;
+                match args.get(i).map(|arg| arg.as_str()) {
+                    Some("1") => options.part = Option::from(1),
+                    Some("2") => options.part = Option::from(2),
+                    _ => return Err(String::from("--part expects 1 or 2"))
+                }
+            },
+            "--input" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => options.input = Option::from(path.clone()),
+                    None => return Err(String::from("--input expects a file or - for stdin"))
+                }
+            },
+            "--stream" => options.stream = true,
+            "--time" => options.time = true,
+            "--format" => {
+                i += 1;
+                match args.get(i).map(|arg| arg.as_str()) {
+                    Some("text") => options.format = Format::Text,
+                    Some("json") => options.format = Format::Json,
+                    _ => return Err(String::from("--format expects text or json"))
+                }
+            },
+            "--render" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => options.render = Option::from(path.clone()),
+                    None => return Err(String::from("--render expects a .pbm, .ppm or .svg file"))
+                }
+            },
+            "--record" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => options.record = Option::from(path.clone()),
+                    None => return Err(String::from("--record expects a directory or a .cast file"))
+                }
+            },
+            "--every" => {
+                i += 1;
+                match args.get(i).and_then(|arg| arg.parse::<usize>().ok()) {
+                    Some(every) if every > 0 => options.every = every,
+                    _ => return Err(String::from("--every expects a positive number"))
+                }
+            },
+            "--scale" => {
+                i += 1;
+                match args.get(i).and_then(|arg| arg.parse::<usize>().ok()) {
+                    Some(scale) if scale > 0 => options.scale = scale,
+                    _ => return Err(String::from("--scale expects a positive number"))
+                }
+            },
+            "--palette" => {
+                i += 1;
+                options.palette = image::parse_palette(args.get(i).map(|arg| arg.as_str()).unwrap_or(""))?;
+            },
+            "--report" => options.report = true,
+            "--csv" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => options.csv = Option::from(path.clone()),
+                    None => return Err(String::from("--csv expects a file"))
+                }
+            },
+            "all" => options.days = available_days(),
+            arg => {
+                let day = arg.parse::<u8>().map_err(|_e| format!("Unknown argument \"{}\"", arg))?;
+                if make_day(day).is_none() {
+                    return Err(format!("Day {} is not solved yet", day));
+                }
+                options.days.push(day);
+            }
+        }
+        i += 1;
+    }
+
+    if options.days.is_empty() {
+        return Err(String::from("No day selected"));
+    }
+    if options.render.is_some() && options.days.len() > 1 {
+        return Err(String::from("--render can only be used with a single day"));
+    }
+    if options.record.is_some() && options.days.len() > 1 {
+        return Err(String::from("--record can only be used with a single day"));
+    }
+    if options.csv.is_some() && options.days.len() > 1 {
+        return Err(String::from("--csv can only be used with a single day"));
+    }
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err(String::from("--input can only be used with a single day"));
+    }
+    if options.stream && (options.render.is_some() || options.record.is_some() || options.report || options.csv.is_some()) {
+        return Err(String::from("--stream can't be combined with --render, --record, --report or --csv"));
+    }
+    return Ok(options);
+}
+
+fn parse_verify_args(args: &[String]) -> Result<(Vec<u8>, String), String> {
+    let mut days = Vec::new();
+    let mut answers = String::from("answers.toml");
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--answers" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => answers = path.clone(),
+                    None => return Err(String::from("--answers expects a file"))
+                }
+            },
+            "all" => days = available_days(),
+            arg => {
+                let day = arg.parse::<u8>().map_err(|_e| format!("Unknown argument \"{}\"", arg))?;
+                if make_day(day).is_none() {
+                    return Err(format!("Day {} is not solved yet", day));
+                }
+                days.push(day);
+            }
+        }
+        i += 1;
+    }
+
+    if days.is_empty() {
+        days = available_days();
+    }
+    return Ok((days, answers));
+}
+
+fn parse_bench_args(args: &[String]) -> Result<bench::BenchOptions, String> {
+    let mut options = bench::BenchOptions {
+        days: Vec::new(),
+        runs: 10,
+        output: String::from("bench_results.json"),
+        threshold: 10.0,
+        accept: false
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--runs" => {
+                i += 1;
+                match args.get(i).and_then(|arg| arg.parse::<usize>().ok()) {
+                    Some(runs) if runs > 0 => options.runs = runs,
+                    _ => return Err(String::from("--runs expects a positive number"))
+                }
+            },
+            "--output" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => options.output = path.clone(),
+                    None => return Err(String::from("--output expects a file"))
+                }
+            },
+            "--threshold" => {
+                i += 1;
+                match args.get(i).and_then(|arg| arg.parse::<f64>().ok()) {
+                    Some(threshold) if threshold >= 0.0 => options.threshold = threshold,
+                    _ => return Err(String::from("--threshold expects a percentage"))
+                }
+            },
+            "--accept" => options.accept = true,
+            "all" => options.days = available_days(),
+            arg => {
+                let day = arg.parse::<u8>().map_err(|_e| format!("Unknown argument \"{}\"", arg))?;
+                if make_day(day).is_none() {
+                    return Err(format!("Day {} is not solved yet", day));
+                }
+                options.days.push(day);
+            }
+        }
+        i += 1;
+    }
+
+    if options.days.is_empty() {
+        options.days = available_days();
+    }
+    return Ok(options);
+}
+
+fn print_answer(day: u8, part: u8, answer: &answer::Answer, duration_ms: f64, options: &Options) {
+    if options.format == Format::Json {
+        println!("{{\"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ms\": {:.3}}}", day, part, answer.to_json(), duration_ms);
+        return;
+    }
+
+    println!("{}.) {}", part, answer);
+    if options.time {
+        println!("   (solved in {:.3} ms)", duration_ms);
+    }
+}
+
+/**
+ * Solve both parts while reading the input line by line, the input is never loaded completely
+ */
+fn stream_day(day: u8, puzzle: &dyn Day, path: &str, options: &Options) -> Result<(), String> {
+    let mut reader: Box<dyn BufRead> = if path == "-" {
+        Box::new(BufReader::new(io::stdin()))
+    }
+    else {
+        Box::new(BufReader::new(File::open(path).map_err(|err| format!("Error while reading puzzle input! {}: {}", path, err))?))
+    };
+
+    let start = Instant::now();
+    let answers = puzzle.stream(&mut reader).ok_or(format!("Day {} can't be solved while streaming the input", day))?;
+    let (part1, part2) = answers.map_err(|err| format!("Error while reading puzzle input! {}", err))?;
+    let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+    for (part, answer) in [(1, part1), (2, part2)].iter() {
+        if options.part.is_none() || options.part == Option::from(*part) {
+            print_answer(day, *part, answer, duration_ms, options);
+        }
+    }
+    return Ok(());
+}
+
+fn run_day(day: u8, options: &Options) -> bool {
+    let mut puzzle = make_day(day).unwrap();
+    let json = options.format == Format::Json;
+    if !json && !options.quiet {
+        title::print_title(day, puzzle.title());
+    }
+
+    // Errors go to stderr in json mode so stdout stays machine-readable
+    let report = |msg: String| if json { eprintln!("{}", msg) } else { println!("{}", msg) };
+    let path = options.input.clone().unwrap_or(puzzle.input_path().to_string());
+    if options.stream {
+        if let Err(msg) = stream_day(day, puzzle.as_ref(), &path, options) {
+            report(msg);
+            return false;
+        }
+        return true;
+    }
+    let input = match Input::from_path(&path) {
+        Ok(input) => input,
+        Err(res) => {
+            report(format!("Error while reading puzzle input! {}: {}", path, res));
+            return false;
+        }
+    };
+
+    let start = Instant::now();
+    if let Err(res) = puzzle.parse(&input) {
+        report(format!("Error while reading puzzle input! {}", res));
+        return false;
+    }
+    if options.time && !json {
+        println!("   (parsed in {:.3} ms)", start.elapsed().as_secs_f64() * 1000.0);
+    }
+
+    for part in 1..=2 {
+        if options.part.is_some() && options.part != Option::from(part) {
+            continue;
+        }
+
+        let start = Instant::now();
+        let answer = if part == 1 { puzzle.part1() } else { puzzle.part2() };
+        print_answer(day, part, &answer, start.elapsed().as_secs_f64() * 1000.0, options);
+    }
+
+    if let Some(path) = options.render.as_ref().filter(|path| path.ends_with(".svg")) {
+        let svg = match puzzle.render_svg() {
+            Some(svg) => svg,
+            None => {
+                report(format!("Day {} has no SVG drawing", day));
+                return false;
+            }
+        };
+        if let Err(err) = fs::write(path, svg) {
+            report(format!("Error while rendering to {}! {}", path, err));
+            return false;
+        }
+        if !json {
+            println!("   (rendered to {})", path);
+        }
+    }
+    else if let Some(path) = &options.render {
+        let mut picture = match puzzle.render() {
+            Some(picture) => picture,
+            None => {
+                report(format!("Day {} has no image to render", day));
+                return false;
+            }
+        };
+        picture.set_palette(&options.palette);
+        if let Err(err) = image::save(path, &picture, options.scale) {
+            report(format!("Error while rendering to {}! {}", path, err));
+            return false;
+        }
+        if !json {
+            println!("   (rendered to {})", path);
+        }
+    }
+
+    if let Some(path) = &options.record {
+        let recorder = match puzzle.record(recorder::Recorder::new(options.every)) {
+            Some(recorder) => recorder,
+            None => {
+                report(format!("Day {} has no simulation to record", day));
+                return false;
+            }
+        };
+        if let Err(err) = recorder.save(path, options.scale, &options.palette) {
+            report(format!("Error while recording to {}! {}", path, err));
+            return false;
+        }
+        if !json {
+            println!("   (recorded {} frames to {})", recorder.frames.len(), path);
+        }
+    }
+
+    if options.report || options.csv.is_some() {
+        let table = match puzzle.report() {
+            Some(table) => table,
+            None => {
+                report(format!("Day {} has no report", day));
+                return false;
+            }
+        };
+        if options.report && !json {
+            print!("\n{}", table);
+        }
+        if let Some(path) = &options.csv {
+            let written = File::create(path).and_then(|file| {
+                let mut out = BufWriter::new(file);
+                table.write_csv(&mut out)?;
+                out.flush()
+            });
+            if let Err(err) = written {
+                report(format!("Error while writing {}! {}", path, err));
+                return false;
+            }
+            if !json {
+                println!("   (report written to {})", path);
+            }
+        }
+    }
+    return true;
+}
+
+/**
+ * Run a day specific command, --input replaces the puzzle input the command falls back to
+ */
+fn run_tool(args: &[String]) -> Result<String, String> {
+    let day = args.first().and_then(|arg| arg.parse::<u8>().ok()).ok_or(String::from("tool expects a day"))?;
+    let mut puzzle = make_day(day).ok_or(format!("Day {} is not solved yet", day))?;
+    let mut path = String::from(puzzle.input_path());
+    let mut tool_args = Vec::new();
+
+    let mut i = 1;
+    while i < args.len() {
+        if args[i] == "--input" {
+            i += 1;
+            path = args.get(i).ok_or(String::from("--input expects a file or - for stdin"))?.clone();
+        }
+        else {
+            tool_args.push(args[i].clone());
+        }
+        i += 1;
+    }
+
+    let input = || Input::from_path(&path).map_err(|err| format!("Error while reading puzzle input! {}: {}", path, err));
+    return puzzle.tool(&tool_args, &input).unwrap_or(Err(format!("Day {} has no tools", day)));
+}
+
+/**
+ * Remove the options that apply to every command from the arguments, returns the color mode and quiet flag
+ */
+fn parse_global_args(args: &mut Vec<String>) -> Result<(title::ColorMode, bool), String> {
+    let mut color = title::ColorMode::Auto;
+    let mut quiet = false;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--color" => {
+                args.remove(i);
+                if i >= args.len() {
+                    return Err(String::from("--color expects auto, always or never"));
+                }
+                color = title::ColorMode::from(&args.remove(i)).ok_or(String::from("--color expects auto, always or never"))?;
+            },
+            "--quiet" => {
+                args.remove(i);
+                quiet = true;
+            },
+            _ => i += 1
+        }
+    }
+    return Ok((color, quiet));
+}
+
+fn main(){
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let (color, quiet) = match parse_global_args(&mut args) {
+        Ok(res) => res,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    title::set_color_mode(color);
+
+    match args.first().map(|arg| arg.as_str()) {
+        Some("run") => {
+            let options = match parse_run_args(&args[1..]) {
+                Ok(options) => Options { quiet: quiet, ..options },
+                Err(err) => {
+                    eprintln!("{}\n{}", err, USAGE);
+                    process::exit(2);
+                }
+            };
+
+            let mut success = true;
+            for day in &options.days {
+                success &= run_day(*day, &options);
+            }
+            if !success {
+                process::exit(1);
+            }
+        },
+        Some("verify") => {
+            let (days, answers) = match parse_verify_args(&args[1..]) {
+                Ok(res) => res,
+                Err(err) => {
+                    eprintln!("{}\n{}", err, USAGE);
+                    process::exit(2);
+                }
+            };
+
+            if !verify::verify(&days, &answers) {
+                process::exit(1);
+            }
+        },
+        Some("bench") => {
+            let options = match parse_bench_args(&args[1..]) {
+                Ok(options) => options,
+                Err(err) => {
+                    eprintln!("{}\n{}", err, USAGE);
+                    process::exit(2);
+                }
+            };
+
+            if !bench::bench(&options) {
+                process::exit(1);
+            }
+        },
+        Some("tool") => {
+            match run_tool(&args[1..]) {
+                Ok(output) => print!("{}", output),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                }
+            }
+        },
+        Some("list") => {
+            for day in available_days() {
+                println!("{:2} {}", day, make_day(day).unwrap().title());
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::fs;
+use colored::*;
+use regex::Regex;
+use crate::input::Input;
+use crate::make_day;
+
+lazy_static! {
+    static ref SECTION_REGEX : Regex = Regex::new(r"^\[day(\d+)\]$").unwrap();
+    static ref ANSWER_REGEX : Regex = Regex::new(r#"^part([12])\s*=\s*(?:"(?P<str>[^"]*)"|(?P<num>-?\d+))$"#).unwrap();
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Status {
+    Pass,
+    Fail,
+    Missing
+}
+
+/**
+ * Parse the answers file, a small subset of toml with [dayXX] sections and partX = "answer" entries
+ */
+fn parse_answers(text: &str) -> Result<HashMap<(u8, u8), String>, String> {
+    let mut answers = HashMap::new();
+    let mut day: Option<u8> = Option::None;
+    for (line_nr, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with("#") {
+            continue;
+        }
+
+        let section = SECTION_REGEX.captures(line);
+        if section.is_some() {
+            day = section.unwrap()[1].parse().ok();
+            continue;
+        }
+
+        let answer = ANSWER_REGEX.captures(line);
+        if answer.is_none() || day.is_none() {
+            return Err(format!("Invalid line {} in answers file: {}", line_nr + 1, raw_line));
+        }
+
+        let caps = answer.unwrap();
+        let value = caps.name("str").or(caps.name("num")).unwrap().as_str();
+        answers.insert((day.unwrap(), caps[1].parse().unwrap()), value.to_string());
+    }
+    return Ok(answers);
+}
+
+fn print_row(day: u8, part: u8, status: Status, answer: &str, expected: Option<&String>) {
+    let status_str = match status {
+        Status::Pass => "pass".green(),
+        Status::Fail => "FAIL".red().bold(),
+        Status::Missing => "missing".yellow()
+    };
+
+    // Only show the first line of multi-line answers like images
+    let mut answer_line = answer.lines().next().unwrap_or("").to_string();
+    if answer.lines().count() > 1 {
+        answer_line += " ...";
+    }
+
+    if status == Status::Fail && expected.is_some() {
+        println!("{:>3} {:>4}  {:<8} {} (expected {})", day, part, status_str, answer_line, expected.unwrap());
+    }
+    else {
+        println!("{:>3} {:>4}  {:<8} {}", day, part, status_str, answer_line);
+    }
+}
+
+/**
+ * Solve the days and compare them with the answers file, returns false if any answer did not match
+ */
+pub fn verify(days: &Vec<u8>, answers_path: &str) -> bool {
+    let answers = match fs::read_to_string(answers_path).map_err(|err| err.to_string()).and_then(|text| parse_answers(&text)) {
+        Ok(answers) => answers,
+        Err(err) => {
+            println!("Error while reading answers from {}! {}", answers_path, err);
+            return false;
+        }
+    };
+
+    let mut counts: HashMap<Status, u32> = HashMap::new();
+    println!("Day Part  Result   Answer");
+    for day in days {
+        let mut puzzle = make_day(*day).unwrap();
+        let parsed = Input::from_path(puzzle.input_path()).map_err(|err| err.into()).and_then(|input| puzzle.parse(&input));
+
+        for part in 1..=2 {
+            let expected = answers.get(&(*day, part));
+            let answer = match &parsed {
+                Ok(_) => if part == 1 { puzzle.part1() } else { puzzle.part2() }.to_string(),
+                Err(err) => format!("Error while reading puzzle input! {}", err)
+            };
+
+            let status = if parsed.is_err() {
+                Status::Fail
+            }
+            else if expected.is_none() {
+                Status::Missing
+            }
+            else if *expected.unwrap() == answer {
+                Status::Pass
+            }
+            else {
+                Status::Fail
+            };
+
+            *counts.entry(status).or_insert(0) += 1;
+            print_row(*day, part, status, &answer, expected);
+        }
+    }
+
+    let failed = *counts.get(&Status::Fail).unwrap_or(&0);
+    println!("{} passed, {} failed, {} missing", counts.get(&Status::Pass).unwrap_or(&0), failed, counts.get(&Status::Missing).unwrap_or(&0));
+    return failed == 0;
+}
@@ -0,0 +1,122 @@
+use std::convert::TryFrom;
+use std::fmt;
+use num::{BigInt, ToPrimitive};
+use crate::ocr;
+
+/**
+ * Solution of a puzzle part, images are stored as rows of lit pixels
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Image(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /**
+     * Text answer if all letters of the image could be read, otherwise the image itself
+     */
+    pub fn from_image(rows: Vec<Vec<bool>>) -> Self {
+        return match ocr::read_text(&rows) {
+            Some(text) if !text.contains('?') => Answer::Text(text),
+            _ => Answer::Image(rows)
+        };
+    }
+
+    pub fn to_json(self: &Self) -> String {
+        return match self {
+            Answer::Number(val) => val.to_string(),
+            Answer::Text(text) => json_string(text),
+            Answer::Image(rows) => {
+                let lines: Vec<String> = rows.iter().map(|row| {
+                    json_string(&row.iter().map(|lit| if *lit { '#' } else { '.' }).collect::<String>())
+                }).collect();
+                format!("[{}]", lines.join(", "))
+            }
+        };
+    }
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut res = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            '\r' => res += "\\r",
+            '\t' => res += "\\t",
+            c if (c as u32) < 0x20 => res += &format!("\\u{:04x}", c as u32),
+            c => res.push(c)
+        }
+    }
+    res += "\"";
+    return res;
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(val) => write!(f, "{}", val),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Image(rows) => {
+                writeln!(f, "< image below >")?;
+                for row in rows {
+                    writeln!(f, "{}", row.iter().map(|lit| if *lit { '█' } else { ' ' }).collect::<String>())?;
+                }
+                return Ok(());
+            }
+        }
+    }
+}
+
+/**
+ * Unsigned numbers that don't fit into an i64 are kept as text like big numbers
+ */
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(val: $t) -> Self {
+                return match i64::try_from(val) {
+                    Ok(number) => Answer::Number(number),
+                    Err(_e) => Answer::Text(val.to_string())
+                };
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, u16, u32, i64, u64, usize);
+
+/**
+ * Big numbers that don't fit into an i64 are kept as text
+ */
+impl From<BigInt> for Answer {
+    fn from(val: BigInt) -> Self {
+        return match val.to_i64() {
+            Some(number) => Answer::Number(number),
+            None => Answer::Text(val.to_string())
+        };
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        return Answer::Text(text.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned_answers() {
+        assert_eq!(Answer::from(42u64), Answer::Number(42));
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(String::from("18446744073709551615")));
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+        assert_eq!(Answer::from(-7i32), Answer::Number(-7));
+    }
+}
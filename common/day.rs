@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::io::BufRead;
+use crate::answer::Answer;
+use crate::image::Picture;
+use crate::input::Input;
+use crate::recorder::Recorder;
+use crate::report::Report;
+
+/**
+ * Extra workload of a day timed by "aoc bench", the setup happens before the closure is created
+ */
+pub struct Benchmark {
+    pub name: &'static str,
+    pub run: Box<dyn Fn()>,
+}
+
+/**
+ * A single puzzle day, parse() has to be called before the parts are solved
+ */
+pub trait Day {
+    fn title(self: &Self) -> &'static str;
+    fn input_path(self: &Self) -> &'static str;
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>>;
+    fn part1(self: &Self) -> Answer;
+    fn part2(self: &Self) -> Answer;
+
+    /**
+     * Picture of the puzzle state for days that produce an image
+     */
+    fn render(self: &Self) -> Option<Picture> {
+        return Option::None;
+    }
+
+    /**
+     * Vector drawing of the puzzle as SVG document for days where pixels don't fit
+     */
+    fn render_svg(self: &Self) -> Option<String> {
+        return Option::None;
+    }
+
+    /**
+     * Run the simulation of the day again and record its frames, None for days without simulation
+     */
+    fn record(self: &Self, _recorder: Recorder) -> Option<Recorder> {
+        return Option::None;
+    }
+
+    /**
+     * Table with details about the solution, None for days without report
+     */
+    fn report(self: &Self) -> Option<Report> {
+        return Option::None;
+    }
+
+    /**
+     * Solve both parts while reading the input line by line, None for days that need the whole input
+     */
+    fn stream(self: &Self, _reader: &mut dyn BufRead) -> Option<Result<(Answer, Answer), Box<dyn Error>>> {
+        return Option::None;
+    }
+
+    /**
+     * Workloads "aoc bench" times besides parsing and the parts, names have to be single words
+     */
+    fn benchmarks(self: &Self) -> Vec<Benchmark> {
+        return Vec::new();
+    }
+
+    /**
+     * Day specific command of "aoc tool", returns the text to print. None for days without tools.
+     * The input is only read when the command calls the loader, load_input() parses it.
+     */
+    fn tool(self: &mut Self, _args: &[String], _input: &dyn Fn() -> Result<Input, String>) -> Option<Result<String, String>> {
+        return Option::None;
+    }
+
+    /**
+     * Read the input with the loader of a tool and parse it
+     */
+    fn load_input(self: &mut Self, input: &dyn Fn() -> Result<Input, String>) -> Result<(), String> {
+        return self.parse(&input()?).map_err(|err| format!("Error while reading puzzle input! {}", err));
+    }
+}
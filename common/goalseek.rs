@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use crate::intcomputer::{Machine, YieldState};
+use crate::symbolic::{self, Linear, SymbolicMachine};
+
+/**
+ * Instructions a single run may execute, programs that take longer count as not halting
+ */
+pub const MAX_STEPS: usize = 1_000_000;
+
+/**
+ * Budget of the symbolic fallback, runs of all forks together and instructions per run
+ */
+pub const SYMBOLIC_RUNS: usize = 100;
+pub const SYMBOLIC_STEPS: usize = 10_000;
+
+/**
+ * Memory cell that gets changed while seeking the goal
+ */
+#[derive(Debug, Clone)]
+pub struct Variable {
+    pub addr: usize,
+    pub range: RangeInclusive<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Symbolic,
+    Linear,
+    Search
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub values: Vec<i64>,
+    pub method: Method,
+}
+
+/**
+ * Run the program with the variables set and read the target cell, None if the program waits
+ * for input or doesn't halt within MAX_STEPS instructions
+ */
+pub fn evaluate(prog: &Vec<i64>, variables: &Vec<Variable>, values: &Vec<i64>, target: usize) -> Option<i64> {
+    let mut memory = prog.clone();
+    for (var, val) in variables.iter().zip(values.iter()) {
+        if var.addr >= memory.len() {
+            memory.resize(var.addr + 1, 0);
+        }
+        memory[var.addr] = *val;
+    }
+
+    let mut machine = Machine::make(&memory, &Vec::new());
+    if !machine.continue_steps(MAX_STEPS) || machine.state != YieldState::Halted {
+        return Option::None;
+    }
+    return Option::from(machine.memory.get(target).copied().unwrap_or(0));
+}
+
+/**
+ * Estimate the target cell as constant + sum(coeff * variable) from one run per variable and
+ * check the estimate on a few more points, None if the result doesn't behave linear
+ */
+pub fn detect_linear(prog: &Vec<i64>, variables: &Vec<Variable>, target: usize) -> Option<Linear> {
+    let base: Vec<i64> = variables.iter().map(|var| *var.range.start()).collect();
+    let base_result = evaluate(prog, variables, &base, target)?;
+
+    let mut lin = Linear {
+        constant: base_result,
+        coeffs: BTreeMap::new()
+    };
+    for (i, var) in variables.iter().enumerate() {
+        if var.range.start() == var.range.end() {
+            continue;
+        }
+        let mut point = base.clone();
+        point[i] += 1;
+        let coeff = evaluate(prog, variables, &point, target)? - base_result;
+        if coeff != 0 {
+            lin.coeffs.insert(i, coeff);
+        }
+        lin.constant -= coeff * base[i];
+    }
+
+    // The ends, the middle and a mix of both catch most programs that only look linear around the start
+    let ends: Vec<i64> = variables.iter().map(|var| *var.range.end()).collect();
+    let middle: Vec<i64> = variables.iter().map(|var| var.range.start() + (var.range.end() - var.range.start()) / 2).collect();
+    let mixed: Vec<i64> = (0..variables.len()).map(|i| if i % 2 == 0 { ends[i] } else { middle[i] }).collect();
+    for point in [ends, middle, mixed] {
+        let expected = lin.constant + lin.coeffs.iter().map(|(i, coeff)| coeff * point[*i]).sum::<i64>();
+        if evaluate(prog, variables, &point, target)? != expected {
+            return Option::None;
+        }
+    }
+    return Option::from(lin);
+}
+
+/**
+ * Next combination of values for the variables starting at index first, false after the last one
+ */
+fn next_values(values: &mut Vec<i64>, variables: &Vec<Variable>, first: usize) -> bool {
+    for i in (first..variables.len()).rev() {
+        if values[i] < *variables[i].range.end() {
+            values[i] += 1;
+            return true;
+        }
+        values[i] = *variables[i].range.start();
+    }
+    return false;
+}
+
+/**
+ * Try every combination, the values of the first variable are split between the threads.
+ * If there are several solutions any of them might be returned.
+ */
+pub fn parallel_search(prog: &Vec<i64>, variables: &Vec<Variable>, target: usize, value: i64) -> Option<Vec<i64>> {
+    if variables.is_empty() {
+        return evaluate(prog, variables, &Vec::new(), target).filter(|result| *result == value).map(|_result| Vec::new());
+    }
+
+    let threads = thread::available_parallelism().map_or(1, |count| count.get()) as i64;
+    let found: Mutex<Option<Vec<i64>>> = Mutex::new(Option::None);
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        for offset in 0..threads {
+            let found = &found;
+            let done = &done;
+            scope.spawn(move || {
+                let mut first = variables[0].range.start() + offset;
+                while first <= *variables[0].range.end() && !done.load(Ordering::Relaxed) {
+                    let mut values: Vec<i64> = variables.iter().map(|var| *var.range.start()).collect();
+                    values[0] = first;
+                    loop {
+                        if evaluate(prog, variables, &values, target) == Option::from(value) {
+                            done.store(true, Ordering::Relaxed);
+                            *found.lock().unwrap() = Option::from(values);
+                            return;
+                        }
+                        if done.load(Ordering::Relaxed) || !next_values(&mut values, variables, 1) {
+                            break;
+                        }
+                    }
+                    first += threads;
+                }
+            });
+        }
+    });
+    return found.into_inner().unwrap();
+}
+
+/**
+ * Run the program with a symbol for every variable and solve the expression that ends up in the
+ * target cell, the symbols are numbered like the variables. Gives up after SYMBOLIC_RUNS runs of
+ * at most SYMBOLIC_STEPS instructions.
+ */
+fn symbolic_search(prog: &Vec<i64>, variables: &Vec<Variable>, target: usize, value: i64) -> Option<Vec<i64>> {
+    let mut machine = SymbolicMachine::make(prog);
+    for var in variables {
+        machine.symbolize(var.addr);
+    }
+    let domains: Vec<RangeInclusive<i64>> = variables.iter().map(|var| var.range.clone()).collect();
+    return symbolic::solve_machine(&machine, &domains, target, value, SYMBOLIC_STEPS, SYMBOLIC_RUNS);
+}
+
+/**
+ * Find values for the variables so the target cell ends up with the value. A result that behaves
+ * linear in the sampled points is solved directly. Otherwise the program is executed symbolically
+ * within a small budget, and if that fails as well all combinations are tried in parallel.
+ */
+pub fn seek(prog: &Vec<i64>, variables: &Vec<Variable>, target: usize, value: i64) -> Option<Solution> {
+    if let Some(lin) = detect_linear(prog, variables, target) {
+        let domains: Vec<RangeInclusive<i64>> = variables.iter().map(|var| var.range.clone()).collect();
+        let mut values: Vec<i64> = variables.iter().map(|var| *var.range.start()).collect();
+        // The samples can miss non-linear spots, so the solution is checked with a real run
+        if symbolic::solve_linear(&lin, &domains, value, &mut values) && evaluate(prog, variables, &values, target) == Option::from(value) {
+            return Option::from(Solution {
+                values: values,
+                method: Method::Linear
+            });
+        }
+    }
+
+    if let Some(values) = symbolic_search(prog, variables, target, value) {
+        if evaluate(prog, variables, &values, target) == Option::from(value) {
+            return Option::from(Solution {
+                values: values,
+                method: Method::Symbolic
+            });
+        }
+    }
+
+    return parallel_search(prog, variables, target, value).map(|values| Solution {
+        values: values,
+        method: Method::Search
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(ranges: Vec<(usize, RangeInclusive<i64>)>) -> Vec<Variable> {
+        return ranges.into_iter().map(|(addr, range)| Variable { addr: addr, range: range }).collect();
+    }
+
+    // mem[0] = mem[9] * 7 + mem[10]
+    const LINEAR: [i64; 11] = [1002, 9, 7, 0, 1, 0, 10, 0, 99, 0, 0];
+    // mem[0] = mem[9] * mem[10]
+    const PRODUCT: [i64; 11] = [2, 9, 10, 0, 99, 0, 0, 0, 0, 0, 0];
+
+    #[test]
+    fn linear_is_detected() {
+        let variables = vars(vec![(9, 0..=99), (10, 0..=99)]);
+        let lin = detect_linear(&LINEAR.to_vec(), &variables, 0).unwrap();
+        assert_eq!(lin.constant, 0);
+        assert_eq!(lin.coeffs.into_iter().collect::<Vec<(usize, i64)>>(), vec![(0, 7), (1, 1)]);
+        assert!(detect_linear(&PRODUCT.to_vec(), &variables, 0).is_none());
+    }
+
+    #[test]
+    fn seek_linear() {
+        let variables = vars(vec![(9, 0..=99), (10, 0..=6)]);
+        let solution = seek(&LINEAR.to_vec(), &variables, 0, 500).unwrap();
+        assert_eq!(solution, Solution { values: vec![71, 3], method: Method::Linear });
+        assert_eq!(seek(&LINEAR.to_vec(), &variables, 0, 1000), Option::None);
+    }
+
+    #[test]
+    fn seek_product() {
+        let variables = vars(vec![(9, 0..=99), (10, 0..=99)]);
+        let solution = seek(&PRODUCT.to_vec(), &variables, 0, 97 * 89).unwrap();
+        assert_eq!(solution.method, Method::Symbolic);
+        let mut sorted = solution.values.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![89, 97]);
+    }
+
+    #[test]
+    fn symbolic_budget() {
+        // Halts early if mem[18] < 0, otherwise mem[0] = mem[18] * mem[18] + 5. The branch depends on
+        // the variable, so the symbolic run forks once per value until SYMBOLIC_RUNS is used up.
+        let prog = vec![1007, 18, 0, 16, 1005, 16, 15, 2, 18, 18, 17, 1001, 17, 5, 0, 99, 0, 0, 0];
+        let variables = vars(vec![(18, 0..=500)]);
+        assert!(detect_linear(&prog, &variables, 0).is_none());
+        assert_eq!(seek(&prog, &variables, 0, 45 * 45 + 5), Option::from(Solution { values: vec![45], method: Method::Symbolic }));
+        assert_eq!(seek(&prog, &variables, 0, 400 * 400 + 5), Option::from(Solution { values: vec![400], method: Method::Search }));
+    }
+
+    #[test]
+    fn loops_count_as_not_halting() {
+        // Jumps back to the start forever unless mem[9] is 0, then sets mem[0] = 5
+        let prog = vec![1005, 9, 0, 1101, 2, 3, 0, 99, 0, 0];
+        let variables = vars(vec![(9, 0..=3)]);
+        assert_eq!(evaluate(&prog, &variables, &vec![1], 0), Option::None);
+        assert_eq!(evaluate(&prog, &variables, &vec![0], 0), Option::from(5));
+        assert!(detect_linear(&prog, &variables, 0).is_none());
+        assert_eq!(seek(&prog, &variables, 0, 5), Option::from(Solution { values: vec![0], method: Method::Symbolic }));
+        assert_eq!(seek(&prog, &variables, 0, 6), Option::None);
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::ops::{Add, Mul, Sub};
+use num::{Num, Signed};
+
+/**
+ * Point on a 2D grid, y grows downwards like on the screen
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn from(x: T, y: T) -> Self {
+        return Point {
+            x: x,
+            y: y
+        };
+    }
+}
+
+impl<T: Num> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        return Point::from(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl<T: Num> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        return Point::from(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        return Point::from(self.x * factor, self.y * factor);
+    }
+}
+
+impl<T: Signed + Copy> Point<T> {
+    pub fn manhattan(self: &Self, other: &Self) -> T {
+        return (self.x - other.x).abs() + (self.y - other.y).abs();
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    UP,
+    RIGHT,
+    DOWN,
+    LEFT
+}
+
+impl Direction {
+    /** All directions clockwise starting with UP */
+    pub const ALL: [Direction; 4] = [Direction::UP, Direction::RIGHT, Direction::DOWN, Direction::LEFT];
+
+    pub fn turn_left(self: &Self) -> Self {
+        return match self {
+            Self::UP => Self::LEFT,
+            Self::LEFT => Self::DOWN,
+            Self::DOWN => Self::RIGHT,
+            Self::RIGHT => Self::UP
+        };
+    }
+
+    pub fn turn_right(self: &Self) -> Self {
+        return match self {
+            Self::UP => Self::RIGHT,
+            Self::RIGHT => Self::DOWN,
+            Self::DOWN => Self::LEFT,
+            Self::LEFT => Self::UP
+        };
+    }
+
+    pub fn reverse(self: &Self) -> Self {
+        return self.turn_left().turn_left();
+    }
+
+    pub fn is_horizontal(self: &Self) -> bool {
+        return *self == Self::LEFT || *self == Self::RIGHT;
+    }
+
+    pub fn offset<T: Signed>(self: &Self) -> Point<T> {
+        return match self {
+            Self::UP => Point::from(T::zero(), -T::one()),
+            Self::RIGHT => Point::from(T::one(), T::zero()),
+            Self::DOWN => Point::from(T::zero(), T::one()),
+            Self::LEFT => Point::from(-T::one(), T::zero())
+        };
+    }
+
+    pub fn move_point<T: Signed + Copy>(self: &Self, pos: Point<T>, steps: T) -> Point<T> {
+        return pos + self.offset() * steps;
+    }
+}
+
+/**
+ * Smallest rectangle containing a set of points, both corners are inclusive
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BoundingBox<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Num + Copy + PartialOrd> BoundingBox<T> {
+    pub fn from_points<'a, I: IntoIterator<Item = &'a Point<T>>>(points: I) -> Option<Self> where T: 'a {
+        let mut bbox: Option<Self> = Option::None;
+        for point in points {
+            match bbox.as_mut() {
+                Some(bbox) => bbox.include(point),
+                None => bbox = Option::from(BoundingBox { min: *point, max: *point })
+            }
+        }
+        return bbox;
+    }
+
+    pub fn include(self: &mut Self, point: &Point<T>) {
+        if point.x < self.min.x { self.min.x = point.x; }
+        if point.y < self.min.y { self.min.y = point.y; }
+        if point.x > self.max.x { self.max.x = point.x; }
+        if point.y > self.max.y { self.max.y = point.y; }
+    }
+
+    pub fn width(self: &Self) -> T {
+        return self.max.x - self.min.x + T::one();
+    }
+
+    pub fn height(self: &Self) -> T {
+        return self.max.y - self.min.y + T::one();
+    }
+}
+
+/**
+ * Grid that only stores the cells that were set, coordinates may be negative
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<V> {
+    pub cells: HashMap<Point<i64>, V>,
+}
+
+impl<V> SparseGrid<V> {
+    pub fn new() -> Self {
+        return SparseGrid {
+            cells: HashMap::new()
+        };
+    }
+
+    pub fn get(self: &Self, pos: &Point<i64>) -> Option<&V> {
+        return self.cells.get(pos);
+    }
+
+    pub fn insert(self: &mut Self, pos: Point<i64>, val: V) {
+        self.cells.insert(pos, val);
+    }
+
+    pub fn len(self: &Self) -> usize {
+        return self.cells.len();
+    }
+
+    /**
+     * Position of any cell matching the predicate
+     */
+    pub fn find<F: Fn(&V) -> bool>(self: &Self, pred: F) -> Option<Point<i64>> {
+        return self.cells.iter().find(|&(_pos, val)| pred(val)).map(|(pos, _val)| *pos);
+    }
+
+    pub fn count<F: Fn(&V) -> bool>(self: &Self, pred: F) -> usize {
+        return self.cells.values().filter(|val| pred(val)).count();
+    }
+
+    pub fn bounding_box(self: &Self) -> Option<BoundingBox<i64>> {
+        return BoundingBox::from_points(self.cells.keys());
+    }
+}
+
+impl<V: Clone> SparseGrid<V> {
+    /**
+     * Copy the cells inside the bounding box into a dense grid, missing cells are set to the background
+     */
+    pub fn to_dense(self: &Self, background: V) -> DenseGrid<V> {
+        let bbox = match self.bounding_box() {
+            Some(bbox) => bbox,
+            None => return DenseGrid::new(0, 0, background)
+        };
+
+        let mut grid = DenseGrid::new(bbox.width() as usize, bbox.height() as usize, background);
+        for (pos, val) in &self.cells {
+            grid.set(&(*pos - bbox.min), val.clone());
+        }
+        return grid;
+    }
+}
+
+/**
+ * Grid with all cells stored row by row, the top left cell is at 0,0
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseGrid<V> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<V>,
+}
+
+impl<V: Clone> DenseGrid<V> {
+    pub fn new(width: usize, height: usize, fill: V) -> Self {
+        return DenseGrid {
+            width: width,
+            height: height,
+            cells: vec![fill; width * height]
+        };
+    }
+
+    pub fn to_rows(self: &Self) -> Vec<Vec<V>> {
+        return (0..self.height).map(|y| self.row(y).to_vec()).collect();
+    }
+}
+
+impl<V> DenseGrid<V> {
+    /**
+     * Grid from cells stored row by row, a last incomplete row is dropped
+     */
+    pub fn from_cells(width: usize, mut cells: Vec<V>) -> Self {
+        let height = if width == 0 { 0 } else { cells.len() / width };
+        cells.truncate(width * height);
+        return DenseGrid {
+            width: width,
+            height: height,
+            cells: cells
+        };
+    }
+
+    pub fn contains(self: &Self, pos: &Point<i64>) -> bool {
+        return pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height;
+    }
+
+    fn index(self: &Self, pos: &Point<i64>) -> Option<usize> {
+        if !self.contains(pos) {
+            return Option::None;
+        }
+        return Option::from(pos.y as usize * self.width + pos.x as usize);
+    }
+
+    /**
+     * Set a cell, returns false if the position is outside of the grid
+     */
+    pub fn set(self: &mut Self, pos: &Point<i64>, val: V) -> bool {
+        return match self.index(pos) {
+            Some(i) => {
+                self.cells[i] = val;
+                true
+            },
+            None => false
+        };
+    }
+
+    pub fn row(self: &Self, y: usize) -> &[V] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn map<U, F: Fn(&V) -> U>(self: &Self, f: F) -> DenseGrid<U> {
+        return DenseGrid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_and_move() {
+        let start: Point<i32> = Point::from(0, 0);
+        assert_eq!(Direction::UP.move_point(start, 3), Point::from(0, -3));
+        assert_eq!(Direction::UP.turn_right(), Direction::RIGHT);
+        assert_eq!(Direction::UP.turn_left(), Direction::LEFT);
+        assert_eq!(Direction::LEFT.reverse(), Direction::RIGHT);
+        for dir in Direction::ALL.iter() {
+            assert_eq!(dir.turn_left().turn_right(), *dir);
+            assert_eq!(dir.reverse().move_point(dir.move_point(start, 5), 5), start);
+        }
+    }
+
+    #[test]
+    fn point_distance() {
+        let pos: Point<i64> = Point::from(2, 3);
+        assert_eq!(pos.manhattan(&Point::from(-1, 5)), 5);
+    }
+
+    #[test]
+    fn sparse_to_dense() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::from(-1, -2), 'a');
+        grid.insert(Point::from(1, 0), 'b');
+        let bbox = grid.bounding_box().unwrap();
+        assert_eq!((bbox.width(), bbox.height()), (3, 3));
+        assert_eq!(grid.to_dense('.').to_rows(), vec![vec!['a', '.', '.'], vec!['.', '.', '.'], vec!['.', '.', 'b']]);
+        assert_eq!(grid.find(|c| *c == 'b'), Option::from(Point::from(1, 0)));
+        assert_eq!(SparseGrid::<char>::new().to_dense('.').cells.len(), 0);
+    }
+
+    #[test]
+    fn dense_access() {
+        let mut grid = DenseGrid::from_cells(3, vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert!(grid.contains(&Point::from(1, 1)));
+        assert!(!grid.contains(&Point::from(3, 0)));
+        assert!(grid.set(&Point::from(0, 1), 0));
+        assert!(!grid.set(&Point::from(-1, 1), 0));
+        assert_eq!(grid.to_rows(), vec![vec![1, 2, 3], vec![0, 5, 6]]);
+        assert_eq!(grid.map(|val| *val > 2).to_rows(), vec![vec![false, false, true], vec![false, true, true]]);
+    }
+}
@@ -0,0 +1,154 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use crate::grid::DenseGrid;
+
+pub type Color = (u8, u8, u8);
+
+pub const BLACK: Color = (0, 0, 0);
+pub const WHITE: Color = (255, 255, 255);
+
+/**
+ * Image where every pixel is an index into the palette
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    pub grid: DenseGrid<usize>,
+    pub palette: Vec<Color>,
+}
+
+impl Picture {
+    pub fn color(self: &Self, index: usize) -> Color {
+        return *self.palette.get(index).unwrap_or(&BLACK);
+    }
+
+    /**
+     * Replace the first colors of the palette
+     */
+    pub fn set_palette(self: &mut Self, colors: &Vec<Color>) {
+        for (i, color) in colors.iter().enumerate() {
+            if i < self.palette.len() {
+                self.palette[i] = *color;
+            }
+            else {
+                self.palette.push(*color);
+            }
+        }
+    }
+}
+
+/**
+ * Parse a comma separated list of colors in hex notation like "#000000,ffffff"
+ */
+pub fn parse_palette(text: &str) -> Result<Vec<Color>, String> {
+    return text.split(",").map(|part| {
+        let hex = part.trim().trim_start_matches('#');
+        let val = u32::from_str_radix(hex, 16).ok().filter(|_val| hex.len() == 6).ok_or(format!("Invalid color \"{}\"", part))?;
+        Ok((((val >> 16) & 0xff) as u8, ((val >> 8) & 0xff) as u8, (val & 0xff) as u8))
+    }).collect();
+}
+
+fn is_dark(color: Color) -> bool {
+    return color.0 as u32 * 299 + color.1 as u32 * 587 + color.2 as u32 * 114 < 128 * 1000;
+}
+
+/**
+ * Write a binary PBM, dark colors of the palette become black pixels
+ */
+pub fn write_pbm<W: Write>(out: &mut W, picture: &Picture, scale: usize) -> io::Result<()> {
+    let grid = &picture.grid;
+    write!(out, "P4\n{} {}\n", grid.width * scale, grid.height * scale)?;
+    for y in 0..grid.height {
+        let mut line = vec![0u8; (grid.width * scale).div_ceil(8)];
+        for (x, index) in grid.row(y).iter().enumerate() {
+            if !is_dark(picture.color(*index)) {
+                continue;
+            }
+            for bit in x * scale..(x + 1) * scale {
+                line[bit / 8] |= 0x80 >> (bit % 8);
+            }
+        }
+        for _i in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+    return Ok(());
+}
+
+/**
+ * Write a binary PPM with the colors of the palette
+ */
+pub fn write_ppm<W: Write>(out: &mut W, picture: &Picture, scale: usize) -> io::Result<()> {
+    let grid = &picture.grid;
+    write!(out, "P6\n{} {}\n255\n", grid.width * scale, grid.height * scale)?;
+    for y in 0..grid.height {
+        let mut line = Vec::with_capacity(grid.width * scale * 3);
+        for index in grid.row(y) {
+            let color = picture.color(*index);
+            for _i in 0..scale {
+                line.extend_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+        for _i in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+    return Ok(());
+}
+
+/**
+ * Save the picture, the format is picked by the file extension (.pbm or .ppm)
+ */
+pub fn save(path: &str, picture: &Picture, scale: usize) -> io::Result<()> {
+    let write: fn(&mut BufWriter<File>, &Picture, usize) -> io::Result<()> = if path.ends_with(".pbm") {
+        write_pbm
+    }
+    else if path.ends_with(".ppm") {
+        write_ppm
+    }
+    else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "only .pbm and .ppm files are supported"));
+    };
+
+    let mut out = BufWriter::new(File::create(path)?);
+    write(&mut out, picture, scale)?;
+    return out.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checker() -> Picture {
+        return Picture {
+            grid: DenseGrid::from_cells(2, vec![0, 1, 1, 0]),
+            palette: vec![BLACK, WHITE]
+        };
+    }
+
+    #[test]
+    fn pbm_scaled() {
+        let mut out = Vec::new();
+        write_pbm(&mut out, &checker(), 3).unwrap();
+        let mut expected = b"P4\n6 6\n".to_vec();
+        expected.extend_from_slice(&[0b11100000, 0b11100000, 0b11100000, 0b00011100, 0b00011100, 0b00011100]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn ppm_palette() {
+        let mut picture = checker();
+        picture.set_palette(&parse_palette("#ff0000").unwrap());
+        let mut out = Vec::new();
+        write_ppm(&mut out, &picture, 1).unwrap();
+        let header = b"P6\n2 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        assert_eq!(&out[header.len()..], &[255, 0, 0, 255, 255, 255, 255, 255, 255, 255, 0, 0]);
+    }
+
+    #[test]
+    fn palette_errors() {
+        assert_eq!(parse_palette("000000, #FFFFFF").unwrap(), vec![BLACK, WHITE]);
+        assert!(parse_palette("fff").is_err());
+        assert!(parse_palette("00000g").is_err());
+    }
+}
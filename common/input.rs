@@ -0,0 +1,33 @@
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+use std::str;
+
+/**
+ * Raw puzzle input together with the name of the file it was read from ("-" for stdin)
+ */
+pub struct Input {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl Input {
+    pub fn from_path(path: &str) -> io::Result<Self> {
+        let mut data = Vec::new();
+        if path == "-" {
+            io::stdin().read_to_end(&mut data)?;
+        }
+        else {
+            data = fs::read(path)?;
+        }
+
+        return Ok(Input {
+            name: path.to_string(),
+            data: data
+        });
+    }
+
+    pub fn text(self: &Self) -> Result<&str, str::Utf8Error> {
+        return str::from_utf8(&self.data);
+    }
+}
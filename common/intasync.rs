@@ -0,0 +1,316 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use crate::intcomputer::{Machine, YieldState};
+
+struct Channel {
+    queue: VecDeque<i64>,
+    closed: bool,
+    dropped: bool,
+    waiting: bool,
+    recv_waker: Option<Waker>,
+    send_waker: Option<Waker>,
+}
+
+/**
+ * Sending side of a single-threaded channel, dropping it ends the stream on the receiving side
+ */
+pub struct Sender {
+    chan: Rc<RefCell<Channel>>,
+}
+
+pub struct Receiver {
+    chan: Rc<RefCell<Channel>>,
+}
+
+pub struct Recv<'a> {
+    chan: &'a Rc<RefCell<Channel>>,
+}
+
+pub struct Request<'a> {
+    chan: &'a Rc<RefCell<Channel>>,
+}
+
+pub fn channel() -> (Sender, Receiver) {
+    let chan = Rc::new(RefCell::new(Channel {
+        queue: VecDeque::new(),
+        closed: false,
+        dropped: false,
+        waiting: false,
+        recv_waker: Option::None,
+        send_waker: Option::None
+    }));
+    return (Sender { chan: chan.clone() }, Receiver { chan: chan });
+}
+
+impl Sender {
+    pub fn send(self: &Self, val: i64) {
+        let mut chan = self.chan.borrow_mut();
+        chan.queue.push_back(val);
+        chan.waiting = false;
+        if let Some(waker) = chan.recv_waker.take() {
+            waker.wake();
+        }
+    }
+
+    /**
+     * Resolves to true once the receiver waits on an empty channel or to false if the receiver was dropped
+     */
+    pub fn request(self: &Self) -> Request<'_> {
+        return Request { chan: &self.chan };
+    }
+}
+
+impl Drop for Sender {
+    fn drop(&mut self) {
+        let mut chan = self.chan.borrow_mut();
+        chan.closed = true;
+        if let Some(waker) = chan.recv_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Receiver {
+    /**
+     * Next value of the stream, None once the sender is dropped and all values are consumed
+     */
+    pub fn next(self: &mut Self) -> Recv<'_> {
+        return Recv { chan: &self.chan };
+    }
+
+    pub fn try_recv(self: &mut Self) -> Option<i64> {
+        return self.chan.borrow_mut().queue.pop_front();
+    }
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        let mut chan = self.chan.borrow_mut();
+        chan.dropped = true;
+        if let Some(waker) = chan.send_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<'a> Future for Recv<'a> {
+    type Output = Option<i64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<i64>> {
+        let mut chan = self.chan.borrow_mut();
+        let val = chan.queue.pop_front();
+        if val.is_some() || chan.closed {
+            return Poll::Ready(val);
+        }
+
+        chan.waiting = true;
+        chan.recv_waker = Option::from(cx.waker().clone());
+        if let Some(waker) = chan.send_waker.take() {
+            waker.wake();
+        }
+        return Poll::Pending;
+    }
+}
+
+impl<'a> Future for Request<'a> {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        let mut chan = self.chan.borrow_mut();
+        if chan.dropped {
+            return Poll::Ready(false);
+        }
+        if chan.waiting && chan.queue.is_empty() {
+            return Poll::Ready(true);
+        }
+
+        chan.send_waker = Option::from(cx.waker().clone());
+        return Poll::Pending;
+    }
+}
+
+/**
+ * Machine that awaits its inputs on a channel and streams its outputs into another one
+ */
+pub struct AsyncMachine {
+    pub machine: Machine,
+    input: Receiver,
+    output: Sender,
+}
+
+impl AsyncMachine {
+    /**
+     * Returns the machine together with the sender for its inputs and the receiver for its outputs
+     */
+    pub fn make(prog_in: &Vec<i64>) -> (Self, Sender, Receiver) {
+        let (in_send, in_recv) = channel();
+        let (out_send, out_recv) = channel();
+        let machine = AsyncMachine {
+            machine: Machine::make(prog_in, &vec![]),
+            input: in_recv,
+            output: out_send
+        };
+        return (machine, in_send, out_recv);
+    }
+
+    /**
+     * Run the program until it halts or the input channel is closed while it waits for input
+     */
+    pub async fn run(mut self) -> Machine {
+        loop {
+            self.machine.continue_program();
+            for val in self.machine.outputs.drain(..) {
+                self.output.send(val);
+            }
+
+            if self.machine.state != YieldState::InputWaiting {
+                break;
+            }
+
+            match self.input.next().await {
+                Some(val) => self.machine.inputs.push_back(val),
+                None => break
+            }
+        }
+        return self.machine;
+    }
+}
+
+struct TaskWaker {
+    id: usize,
+    queue: Arc<Mutex<VecDeque<usize>>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.queue.lock().unwrap().push_back(self.id);
+    }
+}
+
+/**
+ * Minimal single-threaded executor, tasks are polled whenever they got woken up
+ */
+pub struct Executor {
+    tasks: Vec<Option<Pin<Box<dyn Future<Output = ()>>>>>,
+    queue: Arc<Mutex<VecDeque<usize>>>,
+}
+
+impl Executor {
+    pub fn new() -> Self {
+        return Executor {
+            tasks: Vec::new(),
+            queue: Arc::new(Mutex::new(VecDeque::new()))
+        };
+    }
+
+    pub fn spawn<F: Future + 'static>(self: &mut Self, fut: F) {
+        self.queue.lock().unwrap().push_back(self.tasks.len());
+        self.tasks.push(Option::from(Box::pin(async move {
+            fut.await;
+        }) as Pin<Box<dyn Future<Output = ()>>>));
+    }
+
+    /**
+     * Poll tasks until all of them are done or none of them can make progress anymore
+     */
+    pub fn run(self: &mut Self) {
+        loop {
+            let next = self.queue.lock().unwrap().pop_front();
+            let id = match next {
+                Some(id) => id,
+                None => break
+            };
+
+            if self.tasks[id].is_none() {
+                continue;
+            }
+
+            let waker = Waker::from(Arc::new(TaskWaker {
+                id: id,
+                queue: self.queue.clone()
+            }));
+            let mut cx = Context::from_waker(&waker);
+            if self.tasks[id].as_mut().unwrap().as_mut().poll(&mut cx).is_ready() {
+                self.tasks[id] = Option::None;
+            }
+        }
+    }
+
+    /**
+     * Spawn the future, run all tasks and return its result (None if it never finished)
+     */
+    pub fn block_on<F: Future + 'static>(self: &mut Self, fut: F) -> Option<F::Output> {
+        let result = Rc::new(RefCell::new(Option::None));
+        let task_result = result.clone();
+        self.spawn(async move {
+            *task_result.borrow_mut() = Option::from(fut.await);
+        });
+        self.run();
+        return result.borrow_mut().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn awaits_input() {
+        let (machine, input, mut output) = AsyncMachine::make(&vec![3,0,4,0,99]);
+        let mut executor = Executor::new();
+        executor.spawn(machine.run());
+        let echoed = executor.block_on(async move {
+            let requested = input.request().await;
+            assert_eq!(output.try_recv(), Option::None);
+            input.send(7);
+            return (requested, output.next().await, output.next().await);
+        });
+        assert_eq!(echoed, Option::from((true, Option::from(7), Option::None)));
+    }
+
+    #[test]
+    fn streams_between_machines() {
+        // The first machine prints 5 and 6, the second one adds its two inputs
+        let (first, _first_input, mut first_output) = AsyncMachine::make(&vec![104,5,104,6,99]);
+        let (second, second_input, mut second_output) = AsyncMachine::make(&vec![3,12,3,13,1,12,13,14,4,14,99,0,0,0,0]);
+        let mut executor = Executor::new();
+        executor.spawn(second.run());
+        executor.spawn(first.run());
+        executor.spawn(async move {
+            while let Some(val) = first_output.next().await {
+                second_input.send(val);
+            }
+        });
+        let sums = executor.block_on(async move {
+            let mut sums = Vec::new();
+            while let Some(val) = second_output.next().await {
+                sums.push(val);
+            }
+            return sums;
+        });
+        assert_eq!(sums, Option::from(vec![11]));
+    }
+
+    #[test]
+    fn halting() {
+        let (machine, _input, _output) = AsyncMachine::make(&vec![1101,2,3,0,99]);
+        let halted = Executor::new().block_on(machine.run()).unwrap();
+        assert_eq!(halted.state, YieldState::Halted);
+        assert_eq!(halted.memory[0], 5);
+
+        // Closing the input while the machine waits stops it without halting
+        let (machine, input, _output) = AsyncMachine::make(&vec![3,0,99]);
+        drop(input);
+        let waiting = Executor::new().block_on(machine.run()).unwrap();
+        assert_eq!(waiting.state, YieldState::InputWaiting);
+
+        // A task waiting on a channel that never gets a value doesn't finish
+        let (_send, mut recv) = channel();
+        assert_eq!(Executor::new().block_on(async move { recv.next().await }), Option::None);
+    }
+}
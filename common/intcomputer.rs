@@ -30,7 +30,6 @@ pub struct OpCode {
 
 #[derive(Debug, Clone)]
 pub struct ProgramResult {
-    pub memory: Vec<i64>,
     pub outputs: Vec<i64>,
     pub code: EndReason
 }
@@ -56,14 +55,12 @@ impl Machine{
             outputs: Vec::new(),
             code: Option::None,
             rel_base: 0
-        };;
+        };
     }
 
-    #[allow(dead_code)]
     pub fn get_result(self: &Self) -> Option<ProgramResult> {
         if self.state == YieldState::Halted && self.code.is_some() {
             return Option::from(ProgramResult {
-                memory: self.memory.clone(),
                 outputs: self.outputs.clone(),
                 code: self.code.clone().unwrap()
             });
@@ -71,7 +68,6 @@ impl Machine{
         return Option::None;
     }
 
-    #[allow(dead_code)]
     pub fn can_continue(self: &Self) -> bool {
         return self.state != YieldState::Halted;
     }
@@ -119,13 +115,27 @@ impl Machine{
      * Run the program in the machine until the next yield occurs or the end is reached
      */
     pub fn continue_program(self: &mut Self){
+        self.continue_steps(usize::MAX);
+    }
+
+    /**
+     * Like continue_program but executes at most max_steps instructions, returns false if the
+     * limit stopped the program. The machine can be continued afterwards.
+     */
+    pub fn continue_steps(self: &mut Self, max_steps: usize) -> bool {
         if self.state == YieldState::Halted{
-            return;
+            return true;
         }
     
         let mut running = true;
+        let mut steps = 0;
     
         while running && self.counter < self.memory.len(){
+            if steps >= max_steps {
+                return false;
+            }
+            steps += 1;
+
             let code = OpCode::from(self.memory[self.counter]);
     
             match code.code {
@@ -153,7 +163,7 @@ impl Machine{
                     }
                     else {
                         self.state = YieldState::InputWaiting;
-                        return;
+                        return true;
                     }
                     self.counter += 2;
                 },
@@ -227,6 +237,7 @@ impl Machine{
         }
     
         self.state = YieldState::Halted;
+        return true;
     }
 }
 
@@ -268,14 +279,6 @@ fn get_opcode_params(opcode: u8) -> u8{
     }
 }
 
-pub fn split_string(string: String) -> Vec<i64>{
-    let mut parts = Vec::new();
-    for part in string.split(","){
-        parts.push(part.parse().unwrap())
-    }
-    return parts;
-}
-
 fn ensure_memsize(state: &mut Machine, size: usize){
     if state.memory.len() >= size {
         return
@@ -289,9 +292,89 @@ fn ensure_memsize(state: &mut Machine, size: usize){
     state.memory.append(&mut empty_vec);
 }
 
-#[allow(dead_code)]
 pub fn run_program(prog_in: &Vec<i64>, input_in: &Vec<i64>) -> Machine {
     let mut machine = Machine::make(&prog_in, &input_in);
     machine.continue_program();
     return machine;
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outputs(prog: &Vec<i64>, inputs: &Vec<i64>) -> Vec<i64> {
+        return run_program(prog, inputs).get_result().unwrap().outputs;
+    }
+
+    #[test]
+    fn day_02_add_mul() {
+        for (prog, mem) in [
+            (vec![1,9,10,3,2,3,11,0,99,30,40,50], vec![3500,9,10,70,2,3,11,0,99,30,40,50]),
+            (vec![1,0,0,0,99], vec![2,0,0,0,99]),
+            (vec![2,3,0,3,99], vec![2,3,0,6,99]),
+            (vec![2,4,4,5,99,0], vec![2,4,4,5,99,9801]),
+            (vec![1,1,1,4,99,5,6,0,99], vec![30,1,1,4,2,5,6,0,99])] {
+            assert_eq!(run_program(&prog, &vec![]).memory, mem);
+        }
+    }
+
+    #[test]
+    fn day_05_immediate_mode() {
+        assert_eq!(run_program(&vec![1002,4,3,4,33], &vec![]).memory[4], 99);
+        assert_eq!(run_program(&vec![1101,100,-1,4,0], &vec![]).memory[4], 99);
+        assert_eq!(outputs(&vec![3,0,4,0,99], &vec![42]), vec![42]);
+    }
+
+    #[test]
+    fn day_05_comparisons() {
+        for (prog, input, expected) in [
+            (vec![3,9,8,9,10,9,4,9,99,-1,8], 8, 1),
+            (vec![3,9,8,9,10,9,4,9,99,-1,8], 7, 0),
+            (vec![3,9,7,9,10,9,4,9,99,-1,8], 7, 1),
+            (vec![3,9,7,9,10,9,4,9,99,-1,8], 8, 0),
+            (vec![3,3,1108,-1,8,3,4,3,99], 8, 1),
+            (vec![3,3,1108,-1,8,3,4,3,99], 9, 0),
+            (vec![3,3,1107,-1,8,3,4,3,99], 5, 1),
+            (vec![3,3,1107,-1,8,3,4,3,99], 9, 0)] {
+            assert_eq!(outputs(&prog, &vec![input]), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn day_05_jumps() {
+        let prog = vec![3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,
+            1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,
+            999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99];
+        assert_eq!(outputs(&prog, &vec![7]), vec![999]);
+        assert_eq!(outputs(&prog, &vec![8]), vec![1000]);
+        assert_eq!(outputs(&prog, &vec![9]), vec![1001]);
+        assert_eq!(outputs(&vec![3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9], &vec![0]), vec![0]);
+        assert_eq!(outputs(&vec![3,3,1105,-1,9,1101,0,0,12,4,12,99,1], &vec![5]), vec![1]);
+    }
+
+    #[test]
+    fn waits_for_input() {
+        let mut machine = Machine::make(&vec![3,0,4,0,99], &vec![]);
+        machine.continue_program();
+        assert_eq!(machine.state, YieldState::InputWaiting);
+        assert!(machine.get_result().is_none());
+
+        machine.push_input(&vec![7]);
+        machine.continue_program();
+        assert!(!machine.can_continue());
+        assert_eq!(machine.outputs, vec![7]);
+    }
+
+    #[test]
+    fn step_limit() {
+        let mut machine = Machine::make(&vec![1105,1,0], &vec![]);
+        assert!(!machine.continue_steps(1000));
+        assert!(machine.can_continue());
+
+        let mut machine = Machine::make(&vec![1101,1,2,0,99], &vec![]);
+        assert!(!machine.continue_steps(1));
+        assert!(machine.continue_steps(1));
+        assert!(machine.get_result().is_some());
+        assert_eq!(machine.memory[0], 3);
+    }
+}
@@ -0,0 +1,146 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str::{self, FromStr};
+use crate::input::Input;
+
+#[derive(Debug)]
+pub enum LoadError {
+    Parse {
+        token: String,
+        reason: String,
+        line: usize,
+        column: usize,
+        index: usize
+    },
+    DumpSize(usize),
+    DumpRange {
+        value: i64,
+        index: usize
+    },
+    Text(str::Utf8Error)
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Parse { token, reason, line, column, index } => write!(f, "Invalid value \"{}\" at line {}, column {} (cell {}): {}", token, line, column, index, reason),
+            LoadError::DumpSize(len) => write!(f, "Cell dump has {} bytes which is not a multiple of 8", len),
+            LoadError::DumpRange { value, index } => write!(f, "Value {} of cell {} in the dump doesn't fit into 32 bits", value, index),
+            LoadError::Text(err) => write!(f, "Program is not valid text: {}", err)
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+/**
+ * Parse a program in text form. Cells are separated by commas or newlines,
+ * whitespace and empty cells are ignored and everything after a '#' is a comment.
+ * Columns are counted in characters.
+ */
+pub fn parse_program<T: FromStr>(text: &str) -> Result<Vec<T>, LoadError> where T::Err: fmt::Display {
+    let mut memory = Vec::new();
+    for (line_nr, line) in text.lines().enumerate() {
+        let code = match line.find('#') {
+            Some(pos) => &line[..pos],
+            None => line
+        };
+
+        let mut column = 1;
+        for part in code.split(",") {
+            let token = part.trim();
+            if !token.is_empty() {
+                let value = token.parse::<T>().map_err(|err| LoadError::Parse {
+                    token: token.to_string(),
+                    reason: err.to_string(),
+                    line: line_nr + 1,
+                    column: column + (part.chars().count() - part.trim_start().chars().count()),
+                    index: memory.len()
+                })?;
+                memory.push(value);
+            }
+            column += part.chars().count() + 1;
+        }
+    }
+    return Ok(memory);
+}
+
+/**
+ * Parse a binary dump where every cell is stored as a little-endian 64 bit integer
+ */
+pub fn parse_dump(bytes: &[u8]) -> Result<Vec<i64>, LoadError> {
+    if bytes.len() % 8 != 0 {
+        return Err(LoadError::DumpSize(bytes.len()));
+    }
+
+    let mut memory = Vec::with_capacity(bytes.len() / 8);
+    for chunk in bytes.chunks(8) {
+        let mut cell = [0u8; 8];
+        cell.copy_from_slice(chunk);
+        memory.push(i64::from_le_bytes(cell));
+    }
+    return Ok(memory);
+}
+
+/**
+ * Load a program from the puzzle input, files ending with ".bin" are read as binary cell dump
+ */
+pub fn load_program(input: &Input) -> Result<Vec<i64>, LoadError> {
+    if input.name.ends_with(".bin") {
+        return parse_dump(&input.data);
+    }
+    return parse_program(input.text().map_err(|err| LoadError::Text(err))?);
+}
+
+/**
+ * Load a program for the days that run it with 32 bit cells, values that don't fit are an error
+ */
+pub fn load_program_i32(input: &Input) -> Result<Vec<i32>, LoadError> {
+    if input.name.ends_with(".bin") {
+        return parse_dump(&input.data)?.iter().enumerate()
+            .map(|(index, value)| i32::try_from(*value).map_err(|_e| LoadError::DumpRange { value: *value, index: index }))
+            .collect();
+    }
+    return parse_program(input.text().map_err(|err| LoadError::Text(err))?);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_program_text() {
+        assert_eq!(parse_program::<i64>("1,2, 3\n# comment\n4,,5 # trailing\n").unwrap(), vec![1, 2, 3, 4, 5]);
+        match parse_program::<i64>("1,2\n3,x4") {
+            Err(LoadError::Parse { token, line, column, index, .. }) => assert_eq!((token.as_str(), line, column, index), ("x4", 2, 3, 3)),
+            res => panic!("Unexpected result {:?}", res)
+        }
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        match parse_program::<i64>("# ünïcode\n1,\u{a0}2,é") {
+            Err(LoadError::Parse { token, line, column, .. }) => assert_eq!((token.as_str(), line, column), ("é", 2, 6)),
+            res => panic!("Unexpected result {:?}", res)
+        }
+    }
+
+    #[test]
+    fn values_out_of_range() {
+        let err = parse_program::<i32>("1,\n 3000000000,99").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid value \"3000000000\" at line 2, column 2 (cell 1): number too large to fit in target type");
+        assert_eq!(parse_program::<i64>("3000000000").unwrap(), vec![3000000000]);
+
+        let bytes: Vec<u8> = [99i64, 1 << 40].iter().flat_map(|val| val.to_le_bytes().to_vec()).collect();
+        let input = Input { name: String::from("program.bin"), data: bytes };
+        assert_eq!(load_program_i32(&input).unwrap_err().to_string(), "Value 1099511627776 of cell 1 in the dump doesn't fit into 32 bits");
+    }
+
+    #[test]
+    fn parse_dump_cells() {
+        let bytes: Vec<u8> = [99i64, -1].iter().flat_map(|val| val.to_le_bytes().to_vec()).collect();
+        assert_eq!(parse_dump(&bytes).unwrap(), vec![99, -1]);
+        assert!(parse_dump(&bytes[..5]).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+/*
+ * Letters of the font used by the puzzles, 6 rows high and 4 columns wide (except I and Y).
+ * Letters are separated by empty columns, so they are stored without surrounding empty columns.
+ */
+const SMALL_FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/*
+ * Larger font with 10 rows and 6 columns
+ */
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+lazy_static! {
+    static ref GLYPHS : HashMap<String, char> = {
+        let mut glyphs = HashMap::new();
+        for (letter, rows) in SMALL_FONT.iter() {
+            glyphs.insert(rows.join("\n"), *letter);
+        }
+        for (letter, rows) in LARGE_FONT.iter() {
+            glyphs.insert(rows.join("\n"), *letter);
+        }
+        glyphs
+    };
+}
+
+fn glyph_key(img: &Vec<Vec<bool>>, start: usize, end: usize) -> String {
+    let rows: Vec<String> = img.iter().map(|row| {
+        (start..end).map(|x| if *row.get(x).unwrap_or(&false) { '#' } else { '.' }).collect()
+    }).collect();
+    return rows.join("\n");
+}
+
+/**
+ * Read the letters of an image, unknown letters are returned as '?'.
+ * Returns None if the image (without empty rows around it) does not have the height of a known font.
+ */
+pub fn read_text(img: &Vec<Vec<bool>>) -> Option<String> {
+    let lit_rows: Vec<usize> = (0..img.len()).filter(|y| img[*y].iter().any(|lit| *lit)).collect();
+    if lit_rows.is_empty() {
+        return Option::None;
+    }
+
+    let text_img: Vec<Vec<bool>> = img[lit_rows[0]..=lit_rows[lit_rows.len() - 1]].to_vec();
+    if text_img.len() != SMALL_FONT[0].1.len() && text_img.len() != LARGE_FONT[0].1.len() {
+        return Option::None;
+    }
+
+    // Letters are the groups of columns between empty columns
+    let width = text_img.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut text = String::new();
+    let mut start: Option<usize> = Option::None;
+    for x in 0..=width {
+        let empty = text_img.iter().all(|row| !*row.get(x).unwrap_or(&false));
+        if !empty && start.is_none() {
+            start = Option::from(x);
+        }
+        else if empty && start.is_some() {
+            text.push(*GLYPHS.get(&glyph_key(&text_img, start.unwrap(), x)).unwrap_or(&'?'));
+            start = Option::None;
+        }
+    }
+    return Option::from(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render<const N: usize>(font: &[(char, [&str; N])], text: &str) -> Vec<Vec<bool>> {
+        let mut img = vec![Vec::new(); N];
+        for c in text.chars() {
+            let rows = font.iter().find(|(letter, _rows)| *letter == c).unwrap().1;
+            for y in 0..N {
+                img[y].extend(rows[y].chars().map(|pixel| pixel == '#'));
+                img[y].push(false);
+            }
+        }
+        return img;
+    }
+
+    #[test]
+    fn read_small_font() {
+        let alphabet: String = SMALL_FONT.iter().map(|(letter, _rows)| *letter).collect();
+        assert_eq!(read_text(&render(&SMALL_FONT, &alphabet)), Option::from(alphabet));
+    }
+
+    #[test]
+    fn read_large_font() {
+        let alphabet: String = LARGE_FONT.iter().map(|(letter, _rows)| *letter).collect();
+        assert_eq!(read_text(&render(&LARGE_FONT, &alphabet)), Option::from(alphabet));
+    }
+
+    #[test]
+    fn empty_rows_and_unknown_letters() {
+        let mut img = render(&SMALL_FONT, "HI");
+        img.insert(0, vec![false; 4]);
+        img.push(vec![false; 2]);
+        assert_eq!(read_text(&img), Option::from(String::from("HI")));
+
+        img[2][0] = false;
+        assert_eq!(read_text(&img), Option::from(String::from("?I")));
+        assert_eq!(read_text(&img[0..5].to_vec()), Option::None);
+    }
+}
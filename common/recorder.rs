@@ -0,0 +1,168 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::answer::json_string;
+use crate::grid::{BoundingBox, SparseGrid};
+use crate::image::{self, Color, Picture};
+
+/** Seconds between two frames of a cast */
+const CAST_FRAME_TIME: f64 = 0.1;
+
+/**
+ * Records snapshots of a simulation every n steps. Frames are sparse grids of palette indices,
+ * they are aligned on the bounding box of all frames when saved so the coordinates stay in place.
+ */
+pub struct Recorder {
+    every: usize,
+    steps: usize,
+    last_frame: Option<usize>,
+    pub palette: Vec<Color>,
+    pub frames: Vec<SparseGrid<usize>>,
+}
+
+impl Recorder {
+    pub fn new(every: usize) -> Self {
+        return Recorder {
+            every: usize::max(every, 1),
+            steps: 0,
+            last_frame: Option::None,
+            palette: vec![image::BLACK, image::WHITE],
+            frames: Vec::new()
+        };
+    }
+
+    /**
+     * Count a simulation step, the snapshot is only taken on every n-th step
+     */
+    pub fn step<F: FnOnce() -> SparseGrid<usize>>(self: &mut Self, snapshot: F) {
+        if self.steps % self.every == 0 {
+            self.frames.push(snapshot());
+            self.last_frame = Option::from(self.steps);
+        }
+        self.steps += 1;
+    }
+
+    /**
+     * Take a snapshot of the final state unless the last step was already recorded
+     */
+    pub fn finish<F: FnOnce() -> SparseGrid<usize>>(self: &mut Self, snapshot: F) {
+        if self.steps == 0 || self.last_frame != Option::from(self.steps - 1) {
+            self.frames.push(snapshot());
+            self.last_frame = Option::from(self.steps);
+        }
+    }
+
+    pub fn pictures(self: &Self, palette: &Vec<Color>) -> Vec<Picture> {
+        let bbox = BoundingBox::from_points(self.frames.iter().flat_map(|frame| frame.cells.keys()));
+        return self.frames.iter().map(|frame| {
+            let mut aligned = frame.clone();
+            if let Some(bbox) = bbox {
+                // Background in the corners so every frame covers the whole bounding box
+                aligned.cells.entry(bbox.min).or_insert(0);
+                aligned.cells.entry(bbox.max).or_insert(0);
+            }
+
+            let mut picture = Picture {
+                grid: aligned.to_dense(0),
+                palette: self.palette.clone()
+            };
+            picture.set_palette(palette);
+            picture
+        }).collect();
+    }
+
+    /**
+     * Save the frames, files ending with .cast are written as asciinema cast,
+     * everything else is a directory that gets one PPM file per frame
+     */
+    pub fn save(self: &Self, path: &str, scale: usize, palette: &Vec<Color>) -> io::Result<()> {
+        let pictures = self.pictures(palette);
+        if path.ends_with(".cast") {
+            let mut out = BufWriter::new(File::create(path)?);
+            write_cast(&mut out, &pictures)?;
+            return out.flush();
+        }
+
+        fs::create_dir_all(path)?;
+        for (i, picture) in pictures.iter().enumerate() {
+            let file = Path::new(path).join(format!("frame_{:05}.ppm", i));
+            image::save(&file.to_string_lossy(), picture, scale)?;
+        }
+        return Ok(());
+    }
+}
+
+/**
+ * Write the frames as asciinema v2 cast, every pixel is a colored block character
+ */
+pub fn write_cast<W: Write>(out: &mut W, pictures: &Vec<Picture>) -> io::Result<()> {
+    let width = pictures.iter().map(|picture| picture.grid.width).max().unwrap_or(0);
+    let height = pictures.iter().map(|picture| picture.grid.height).max().unwrap_or(0);
+    writeln!(out, "{{\"version\": 2, \"width\": {}, \"height\": {}}}", width, height)?;
+
+    for (i, picture) in pictures.iter().enumerate() {
+        let mut text = String::from("\x1b[2J\x1b[H");
+        for y in 0..picture.grid.height {
+            let mut current: Option<Color> = Option::None;
+            for index in picture.grid.row(y) {
+                let color = picture.color(*index);
+                if current != Option::from(color) {
+                    text += &format!("\x1b[38;2;{};{};{}m", color.0, color.1, color.2);
+                    current = Option::from(color);
+                }
+                text.push('█');
+            }
+            text += "\x1b[0m\r\n";
+        }
+        writeln!(out, "[{:.3}, \"o\", {}]", i as f64 * CAST_FRAME_TIME, json_string(&text))?;
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Point;
+
+    fn frame(x: i64) -> SparseGrid<usize> {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::from(x, 0), 1);
+        return grid;
+    }
+
+    #[test]
+    fn records_every_nth_step() {
+        let mut recorder = Recorder::new(3);
+        for x in 0..7 {
+            recorder.step(|| frame(x));
+        }
+        recorder.finish(|| frame(6));
+        assert_eq!(recorder.frames, vec![frame(0), frame(3), frame(6)]);
+
+        recorder.step(|| frame(7));
+        recorder.finish(|| frame(7));
+        assert_eq!(recorder.frames.len(), 4);
+    }
+
+    #[test]
+    fn frames_are_aligned() {
+        let mut recorder = Recorder::new(1);
+        recorder.step(|| frame(-1));
+        recorder.step(|| frame(2));
+        let pictures = recorder.pictures(&vec![]);
+        assert_eq!(pictures[0].grid.cells, vec![1, 0, 0, 0]);
+        assert_eq!(pictures[1].grid.cells, vec![0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn cast_format() {
+        let mut recorder = Recorder::new(1);
+        recorder.step(|| frame(0));
+        let mut out = Vec::new();
+        write_cast(&mut out, &recorder.pictures(&vec![(1, 2, 3)])).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 1, \"height\": 1}");
+        assert_eq!(lines[1], "[0.000, \"o\", \"\\u001b[2J\\u001b[H\\u001b[38;2;255;255;255m█\\u001b[0m\\r\\n\"]");
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+use std::io::{self, Write};
+
+/**
+ * Table with details about the solution of a day, printed with --report or exported as CSV
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    pub notes: Vec<String>,
+}
+
+impl Report {
+    pub fn new(columns: &[&str]) -> Self {
+        return Report {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            rows: Vec::new(),
+            notes: Vec::new()
+        };
+    }
+
+    pub fn add_row(self: &mut Self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    /**
+     * Line of text printed below the table, notes are not part of the CSV export
+     */
+    pub fn add_note(self: &mut Self, note: String) {
+        self.notes.push(note);
+    }
+
+    fn widths(self: &Self) -> Vec<usize> {
+        return (0..self.columns.len()).map(|i| {
+            self.rows.iter().filter_map(|row| row.get(i)).map(|cell| cell.chars().count())
+                .fold(self.columns[i].chars().count(), usize::max)
+        }).collect();
+    }
+
+    /**
+     * Write the report as CSV, cells with separators, quotes or line breaks are quoted
+     */
+    pub fn write_csv<W: Write>(self: &Self, out: &mut W) -> io::Result<()> {
+        for row in std::iter::once(&self.columns).chain(self.rows.iter()) {
+            let cells: Vec<String> = row.iter().map(|cell| csv_cell(cell)).collect();
+            writeln!(out, "{}", cells.join(","))?;
+        }
+        return Ok(());
+    }
+}
+
+fn csv_cell(cell: &str) -> String {
+    if cell.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        return format!("\"{}\"", cell.replace("\"", "\"\""));
+    }
+    return cell.to_string();
+}
+
+/**
+ * Aligned text table, the first column is left aligned and all others right aligned
+ */
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let widths = self.widths();
+        for (index, row) in std::iter::once(&self.columns).chain(self.rows.iter()).enumerate() {
+            let cells: Vec<String> = row.iter().zip(widths.iter()).enumerate().map(|(i, (cell, width))| {
+                if i == 0 { format!("{:<w$}", cell, w = width) } else { format!("{:>w$}", cell, w = width) }
+            }).collect();
+            writeln!(f, "{}", cells.join("  ").trim_end())?;
+
+            if index == 0 {
+                let line: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+                writeln!(f, "{}", line.join("  "))?;
+            }
+        }
+        for note in &self.notes {
+            writeln!(f, "{}", note)?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Report {
+        let mut report = Report::new(&["name", "value"]);
+        report.add_row(vec![String::from("a"), String::from("1,5")]);
+        report.add_row(vec![String::from("say \"hi\""), String::from("22")]);
+        report.add_note(String::from("done"));
+        return report;
+    }
+
+    #[test]
+    fn text_table() {
+        assert_eq!(example().to_string(), "name      value\n--------  -----\na           1,5\nsay \"hi\"     22\ndone\n");
+    }
+
+    #[test]
+    fn row_like_header() {
+        let mut report = Report::new(&["a", "b"]);
+        report.add_row(vec![String::from("a"), String::from("b")]);
+        assert_eq!(report.to_string(), "a  b\n-  -\na  b\n");
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let mut out = Vec::new();
+        example().write_csv(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "name,value\na,\"1,5\"\n\"say \"\"hi\"\"\",22\n");
+    }
+}
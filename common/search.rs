@@ -0,0 +1,107 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/**
+ * Distances of all reached nodes and the node each one was reached from
+ */
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    pub distances: HashMap<N, u64>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    fn new(start: N) -> Self {
+        let mut distances = HashMap::new();
+        distances.insert(start, 0);
+        return SearchResult {
+            distances: distances,
+            parents: HashMap::new()
+        };
+    }
+
+    pub fn distance(self: &Self, node: &N) -> Option<u64> {
+        return self.distances.get(node).copied();
+    }
+
+    /**
+     * Nodes from the start up to and including the target, None if the target wasn't reached
+     */
+    pub fn path(self: &Self, target: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(target) {
+            return Option::None;
+        }
+
+        let mut path = vec![target.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        return Option::from(path);
+    }
+}
+
+/**
+ * Breadth first search over the whole reachable graph, every edge costs 1
+ */
+pub fn bfs<N, F, I>(start: N, mut neighbors: F) -> SearchResult<N>
+    where N: Clone + Eq + Hash, F: FnMut(&N) -> I, I: IntoIterator<Item = N> {
+    let mut result = SearchResult::new(start.clone());
+    let mut queue = VecDeque::from(vec![start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = result.distances[&node];
+        for next in neighbors(&node) {
+            if result.distances.contains_key(&next) {
+                continue;
+            }
+            result.distances.insert(next.clone(), distance + 1);
+            result.parents.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+    }
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Direction, Point};
+
+    const MAZE: [&str; 5] = [
+        "#######",
+        "#S..#.#",
+        "#.#.#.#",
+        "#.#...#",
+        "###.#E#",
+    ];
+
+    fn open(pos: &Point<i64>) -> bool {
+        return MAZE.get(pos.y as usize).and_then(|row| row.chars().nth(pos.x as usize)).map_or(false, |c| c != '#');
+    }
+
+    fn open_neighbors(pos: &Point<i64>) -> Vec<Point<i64>> {
+        return Direction::ALL.iter().map(|dir| dir.move_point(*pos, 1)).filter(open).collect();
+    }
+
+    fn find(c: char) -> Point<i64> {
+        for (y, row) in MAZE.iter().enumerate() {
+            if let Some(x) = row.find(c) {
+                return Point::from(x as i64, y as i64);
+            }
+        }
+        panic!("{} not in maze", c);
+    }
+
+    #[test]
+    fn bfs_maze() {
+        let result = bfs(find('S'), open_neighbors);
+        let end = find('E');
+        assert_eq!(result.distance(&end), Option::from(7));
+        let path = result.path(&end).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], find('S'));
+        assert!(path.windows(2).all(|step| step[0].manhattan(&step[1]) == 1));
+        assert_eq!(result.path(&Point::from(0, 0)), Option::None);
+    }
+}
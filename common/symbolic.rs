@@ -0,0 +1,583 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+use crate::intcomputer::{OpCode, Addressing};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(i64),
+    Symbol(usize),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    LessThan(Box<Expr>, Box<Expr>),
+    Equals(Box<Expr>, Box<Expr>),
+    // Read from a symbolic address out of a snapshot of the memory at the time of the read
+    Load(Box<Expr>, Rc<Vec<Expr>>)
+}
+
+/**
+ * Reason why the symbolic execution stopped, the symbolic variants carry the expression that would have to be concrete
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolicEnd {
+    EndCode,
+    CodeUnknown,
+    EndProgram,
+    InputWaiting,
+    SymbolicCode(Expr),
+    SymbolicAddress(Expr),
+    SymbolicJump(Expr),
+    StepLimit
+}
+
+/**
+ * Expression of the form constant + sum(coeff * symbol)
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Linear {
+    pub constant: i64,
+    pub coeffs: BTreeMap<usize, i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SymbolicMachine {
+    pub counter: usize,
+    pub memory: Vec<Expr>,
+    pub outputs: Vec<Expr>,
+    pub rel_base: i64,
+    pub symbols: usize,
+    pub code: Option<SymbolicEnd>,
+}
+
+impl Expr {
+    pub fn add(a: Expr, b: Expr) -> Expr {
+        return match (a.as_const(), b.as_const()) {
+            (Some(x), Some(y)) => Expr::Const(x + y),
+            (Some(0), _) => b,
+            (_, Some(0)) => a,
+            _ => Expr::Add(Box::new(a), Box::new(b))
+        }
+    }
+
+    pub fn mul(a: Expr, b: Expr) -> Expr {
+        return match (a.as_const(), b.as_const()) {
+            (Some(x), Some(y)) => Expr::Const(x * y),
+            (Some(0), _) | (_, Some(0)) => Expr::Const(0),
+            (Some(1), _) => b,
+            (_, Some(1)) => a,
+            _ => Expr::Mul(Box::new(a), Box::new(b))
+        }
+    }
+
+    pub fn less_than(a: Expr, b: Expr) -> Expr {
+        return match (a.as_const(), b.as_const()) {
+            (Some(x), Some(y)) => Expr::Const(if x < y { 1 } else { 0 }),
+            _ if a == b => Expr::Const(0),
+            _ => Expr::LessThan(Box::new(a), Box::new(b))
+        }
+    }
+
+    pub fn equals(a: Expr, b: Expr) -> Expr {
+        return match (a.as_const(), b.as_const()) {
+            (Some(x), Some(y)) => Expr::Const(if x == y { 1 } else { 0 }),
+            _ if a == b => Expr::Const(1),
+            _ => Expr::Equals(Box::new(a), Box::new(b))
+        }
+    }
+
+    pub fn as_const(self: &Self) -> Option<i64> {
+        return match self {
+            Expr::Const(val) => Option::from(*val),
+            _ => Option::None
+        }
+    }
+
+    /**
+     * Evaluate the expression with concrete values for every symbol (indexed by symbol id)
+     */
+    pub fn eval(self: &Self, values: &Vec<i64>) -> i64 {
+        return match self {
+            Expr::Const(val) => *val,
+            Expr::Symbol(sym) => values[*sym],
+            Expr::Add(a, b) => a.eval(values) + b.eval(values),
+            Expr::Mul(a, b) => a.eval(values) * b.eval(values),
+            Expr::LessThan(a, b) => if a.eval(values) < b.eval(values) { 1 } else { 0 },
+            Expr::Equals(a, b) => if a.eval(values) == b.eval(values) { 1 } else { 0 },
+            Expr::Load(addr, memory) => load_cell(memory, addr.eval(values)).eval(values)
+        }
+    }
+
+    /**
+     * Replace a symbol with a concrete value and fold everything that became constant
+     */
+    pub fn substitute(self: &Self, symbol: usize, value: i64) -> Expr {
+        return match self {
+            Expr::Const(val) => Expr::Const(*val),
+            Expr::Symbol(sym) => if *sym == symbol { Expr::Const(value) } else { Expr::Symbol(*sym) },
+            Expr::Add(a, b) => Expr::add(a.substitute(symbol, value), b.substitute(symbol, value)),
+            Expr::Mul(a, b) => Expr::mul(a.substitute(symbol, value), b.substitute(symbol, value)),
+            Expr::LessThan(a, b) => Expr::less_than(a.substitute(symbol, value), b.substitute(symbol, value)),
+            Expr::Equals(a, b) => Expr::equals(a.substitute(symbol, value), b.substitute(symbol, value)),
+            Expr::Load(addr, memory) => {
+                let addr_sub = addr.substitute(symbol, value);
+                if addr_sub.as_const().is_some() {
+                    return load_cell(memory, addr_sub.as_const().unwrap()).substitute(symbol, value);
+                }
+                let memory_sub = memory.iter().map(|cell| cell.substitute(symbol, value)).collect();
+                Expr::Load(Box::new(addr_sub), Rc::new(memory_sub))
+            }
+        }
+    }
+
+    pub fn symbols(self: &Self) -> Vec<usize> {
+        let mut symbols = Vec::new();
+        self.collect_symbols(&mut symbols);
+        symbols.sort();
+        symbols.dedup();
+        return symbols;
+    }
+
+    fn collect_symbols(self: &Self, symbols: &mut Vec<usize>) {
+        match self {
+            Expr::Const(_) => {},
+            Expr::Symbol(sym) => symbols.push(*sym),
+            Expr::Add(a, b) | Expr::Mul(a, b) | Expr::LessThan(a, b) | Expr::Equals(a, b) => {
+                a.collect_symbols(symbols);
+                b.collect_symbols(symbols);
+            },
+            // Symbols inside the memory snapshot only matter once the address is known
+            Expr::Load(addr, _) => addr.collect_symbols(symbols)
+        }
+    }
+
+    /**
+     * Returns the linear form of the expression or None if it contains products of symbols or comparisons
+     */
+    pub fn to_linear(self: &Self) -> Option<Linear> {
+        return match self {
+            Expr::Const(val) => Option::from(Linear {
+                constant: *val,
+                coeffs: BTreeMap::new()
+            }),
+            Expr::Symbol(sym) => {
+                let mut coeffs = BTreeMap::new();
+                coeffs.insert(*sym, 1);
+                Option::from(Linear {
+                    constant: 0,
+                    coeffs: coeffs
+                })
+            },
+            Expr::Add(a, b) => {
+                let mut lin_a = a.to_linear()?;
+                let lin_b = b.to_linear()?;
+                lin_a.constant += lin_b.constant;
+                for (sym, coeff) in lin_b.coeffs {
+                    *lin_a.coeffs.entry(sym).or_insert(0) += coeff;
+                }
+                lin_a.coeffs.retain(|_sym, coeff| *coeff != 0);
+                Option::from(lin_a)
+            },
+            Expr::Mul(a, b) => {
+                let lin_a = a.to_linear()?;
+                let lin_b = b.to_linear()?;
+                if lin_a.coeffs.is_empty() {
+                    Option::from(lin_b.scale(lin_a.constant))
+                }
+                else if lin_b.coeffs.is_empty() {
+                    Option::from(lin_a.scale(lin_b.constant))
+                }
+                else {
+                    Option::None
+                }
+            },
+            Expr::LessThan(_, _) | Expr::Equals(_, _) | Expr::Load(_, _) => Option::None
+        }
+    }
+}
+
+impl Linear {
+    fn scale(mut self: Self, factor: i64) -> Self {
+        self.constant *= factor;
+        for coeff in self.coeffs.values_mut() {
+            *coeff *= factor;
+        }
+        self.coeffs.retain(|_sym, coeff| *coeff != 0);
+        return self;
+    }
+}
+
+impl SymbolicMachine {
+    pub fn make(prog_in: &Vec<i64>) -> Self {
+        return Self {
+            counter: 0,
+            memory: prog_in.iter().map(|val| Expr::Const(*val)).collect(),
+            outputs: Vec::new(),
+            rel_base: 0,
+            symbols: 0,
+            code: Option::None
+        };
+    }
+
+    fn new_symbol(self: &mut Self) -> usize {
+        self.symbols += 1;
+        return self.symbols - 1;
+    }
+
+    /**
+     * Replace the memory cell at the address with a new symbol and return the symbol id
+     */
+    pub fn symbolize(self: &mut Self, addr: usize) -> usize {
+        let sym = self.new_symbol();
+        self.ensure_memsize(addr + 1);
+        self.memory[addr] = Expr::Symbol(sym);
+        return sym;
+    }
+
+    /**
+     * Replace a symbol everywhere in the machine with a concrete value
+     */
+    pub fn concretize(self: &mut Self, symbol: usize, value: i64) {
+        self.memory = self.memory.iter().map(|cell| cell.substitute(symbol, value)).collect();
+        self.outputs = self.outputs.iter().map(|cell| cell.substitute(symbol, value)).collect();
+    }
+
+    fn ensure_memsize(self: &mut Self, size: usize) {
+        while self.memory.len() < size {
+            self.memory.push(Expr::Const(0));
+        }
+    }
+
+    fn cell(self: &Self, addr: usize) -> Expr {
+        if addr >= self.memory.len() {
+            return Expr::Const(0);
+        }
+        return self.memory[addr].clone();
+    }
+
+    fn read(self: &Self, addr: Expr) -> Expr {
+        if addr.as_const().is_some() {
+            return load_cell(&self.memory, addr.as_const().unwrap());
+        }
+        return Expr::Load(Box::new(addr), Rc::new(self.memory.clone()));
+    }
+
+    fn get_param(self: &Self, pos: u8, mode: Addressing) -> Expr {
+        let raw = self.cell(self.counter + pos as usize);
+        return match mode {
+            Addressing::Immediate => raw,
+            Addressing::Position => self.read(raw),
+            Addressing::Relative => self.read(Expr::add(raw, Expr::Const(self.rel_base)))
+        }
+    }
+
+    fn set_param(self: &mut Self, pos: u8, mode: Addressing, val: Expr) -> Result<(), SymbolicEnd> {
+        let raw = self.cell(self.counter + pos as usize);
+        let addr = match mode {
+            Addressing::Immediate => (self.counter + pos as usize) as i64,
+            Addressing::Position => raw.as_const().ok_or(SymbolicEnd::SymbolicAddress(raw))?,
+            Addressing::Relative => raw.as_const().ok_or(SymbolicEnd::SymbolicAddress(raw))? + self.rel_base
+        } as usize;
+        self.ensure_memsize(addr + 1);
+        self.memory[addr] = val;
+        return Ok(());
+    }
+
+    fn step(self: &mut Self) -> Result<(), SymbolicEnd> {
+        let op = self.cell(self.counter);
+        let code = OpCode::from(op.as_const().ok_or(SymbolicEnd::SymbolicCode(op))?);
+
+        match code.code {
+            1 | 2 | 7 | 8 => {
+                let a = self.get_param(1, code.addr_mode[0]);
+                let b = self.get_param(2, code.addr_mode[1]);
+                let val = match code.code {
+                    1 => Expr::add(a, b),
+                    2 => Expr::mul(a, b),
+                    7 => Expr::less_than(a, b),
+                    _ => Expr::equals(a, b)
+                };
+                self.set_param(3, code.addr_mode[2], val)?;
+                self.counter += 4;
+            },
+            // The machine has no inputs, the goal seeker only varies memory cells
+            3 => return Err(SymbolicEnd::InputWaiting),
+            4 => {
+                let val = self.get_param(1, code.addr_mode[0]);
+                self.outputs.push(val);
+                self.counter += 2;
+            },
+            5 | 6 => {
+                let cond = self.get_param(1, code.addr_mode[0]);
+                let cond_val = cond.as_const().ok_or(SymbolicEnd::SymbolicJump(cond))?;
+                if (cond_val != 0) == (code.code == 5) {
+                    let target = self.get_param(2, code.addr_mode[1]);
+                    self.counter = target.as_const().ok_or(SymbolicEnd::SymbolicJump(target))? as usize;
+                }
+                else {
+                    self.counter += 3;
+                }
+            },
+            9 => {
+                let offset = self.get_param(1, code.addr_mode[0]);
+                self.rel_base += offset.as_const().ok_or(SymbolicEnd::SymbolicAddress(offset))?;
+                self.counter += 2;
+            },
+            99 => return Err(SymbolicEnd::EndCode),
+            _ => return Err(SymbolicEnd::CodeUnknown)
+        }
+        return Ok(());
+    }
+
+    /**
+     * Run the program until it ends, waits for input, a jump or write address depends on a symbol,
+     * or max_steps instructions were executed. Reads from symbolic addresses are kept as Expr::Load.
+     * The counter is left on the instruction that stopped the execution.
+     */
+    pub fn continue_program(self: &mut Self, max_steps: usize) -> SymbolicEnd {
+        let mut steps = 0;
+        let mut end = SymbolicEnd::EndProgram;
+        while self.counter < self.memory.len() {
+            if steps >= max_steps {
+                end = SymbolicEnd::StepLimit;
+                break;
+            }
+
+            let result = self.step();
+            if result.is_err() {
+                end = result.unwrap_err();
+                break;
+            }
+            steps += 1;
+        }
+        self.code = Option::from(end.clone());
+        return end;
+    }
+}
+
+fn load_cell(memory: &Vec<Expr>, addr: i64) -> Expr {
+    if addr < 0 || addr as usize >= memory.len() {
+        return Expr::Const(0);
+    }
+    return memory[addr as usize].clone();
+}
+
+fn domain_size(domain: &RangeInclusive<i64>) -> i64 {
+    return domain.end() - domain.start() + 1;
+}
+
+/**
+ * Solve a linear expression for the target, the found symbol values are written into values
+ */
+pub fn solve_linear(lin: &Linear, domains: &Vec<RangeInclusive<i64>>, target: i64, values: &mut Vec<i64>) -> bool {
+    if lin.coeffs.is_empty() {
+        return lin.constant == target;
+    }
+
+    if lin.coeffs.len() == 1 {
+        let (sym, coeff) = lin.coeffs.iter().next().unwrap();
+        let rest = target - lin.constant;
+        if rest % coeff != 0 || !domains[*sym].contains(&(rest / coeff)) {
+            return false;
+        }
+        values[*sym] = rest / coeff;
+        return true;
+    }
+
+    // Enumerate the smallest domain and keep the larger ones for the direct solution
+    let sym = *lin.coeffs.keys().min_by_key(|sym| domain_size(&domains[**sym])).unwrap();
+    let coeff = lin.coeffs[&sym];
+    for val in domains[sym].clone() {
+        let mut reduced = lin.clone();
+        reduced.coeffs.remove(&sym);
+        reduced.constant += coeff * val;
+        if solve_linear(&reduced, domains, target, values) {
+            values[sym] = val;
+            return true;
+        }
+    }
+    return false;
+}
+
+fn search(expr: &Expr, domains: &Vec<RangeInclusive<i64>>, target: i64, values: &mut Vec<i64>) -> bool {
+    let lin = expr.to_linear();
+    if lin.is_some() {
+        return solve_linear(&lin.unwrap(), domains, target, values);
+    }
+
+    let symbols = expr.symbols();
+    if symbols.is_empty() {
+        return expr.eval(values) == target;
+    }
+
+    // Fix one symbol at a time until the remaining expression becomes linear
+    let sym = *symbols.iter().min_by_key(|sym| domain_size(&domains[**sym])).unwrap();
+    for val in domains[sym].clone() {
+        if search(&expr.substitute(sym, val), domains, target, values) {
+            values[sym] = val;
+            return true;
+        }
+    }
+    return false;
+}
+
+/**
+ * Find values for all symbols (domains are indexed by symbol id) so that the expression evaluates to the target.
+ * Linear expressions are solved directly, otherwise symbols get fixed one by one until the rest is linear.
+ * Symbols not used in the expression are set to the start of their domain.
+ */
+pub fn solve(expr: &Expr, domains: &Vec<RangeInclusive<i64>>, target: i64) -> Option<Vec<i64>> {
+    let mut values: Vec<i64> = domains.iter().map(|domain| *domain.start()).collect();
+    if search(expr, domains, target, &mut values) {
+        return Option::from(values);
+    }
+    return Option::None;
+}
+
+/**
+ * Largest domain the execution is forked over, larger ones make solve_machine give up
+ */
+pub const MAX_FORKS: i64 = 1000;
+
+/**
+ * Run the machine symbolically and find values for its symbols (domains are indexed by symbol id)
+ * so that the target cell equals the value. If the control flow or a write address depends
+ * on a symbol, the execution is forked for every value in the domain of that symbol as long as the
+ * domain has at most MAX_FORKS values. Every run executes at most max_steps instructions and all
+ * forks together make at most max_runs runs, so the work is bounded by max_steps * max_runs.
+ */
+pub fn solve_machine(machine: &SymbolicMachine, domains: &Vec<RangeInclusive<i64>>, target: usize, value: i64, max_steps: usize, max_runs: usize) -> Option<Vec<i64>> {
+    let mut runs_left = max_runs;
+    return solve_fork(machine, domains, target, value, max_steps, &mut runs_left);
+}
+
+fn solve_fork(machine: &SymbolicMachine, domains: &Vec<RangeInclusive<i64>>, target: usize, value: i64, max_steps: usize, runs_left: &mut usize) -> Option<Vec<i64>> {
+    if *runs_left == 0 {
+        return Option::None;
+    }
+    *runs_left -= 1;
+
+    let mut run = machine.clone();
+    let end = run.continue_program(max_steps);
+    let blocker = match end {
+        SymbolicEnd::SymbolicCode(expr) | SymbolicEnd::SymbolicAddress(expr) | SymbolicEnd::SymbolicJump(expr) => expr,
+        SymbolicEnd::EndCode | SymbolicEnd::EndProgram => {
+            return solve(&run.cell(target), domains, value);
+        },
+        _ => return Option::None
+    };
+
+    let symbols = blocker.symbols();
+    let sym = *symbols.iter().min_by_key(|sym| domain_size(&domains[**sym])).unwrap();
+    if domain_size(&domains[sym]) > MAX_FORKS {
+        return Option::None;
+    }
+    for val in domains[sym].clone() {
+        let mut fork = machine.clone();
+        fork.concretize(sym, val);
+        let mut fork_domains = domains.clone();
+        fork_domains[sym] = val..=val;
+        let result = solve_fork(&fork, &fork_domains, target, value, max_steps, runs_left);
+        if result.is_some() || *runs_left == 0 {
+            return result;
+        }
+    }
+    return Option::None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcomputer;
+
+    fn domains(ranges: &[RangeInclusive<i64>]) -> Vec<RangeInclusive<i64>> {
+        return ranges.to_vec();
+    }
+
+    #[test]
+    fn builds_expressions() {
+        // mem[19] = a + b, mem[20] = a * 3, mem[21] = a < b, mem[22] = a == 5 with a and b in mem[17] and mem[18]
+        let prog = vec![1, 17, 18, 19, 1002, 17, 3, 20, 7, 17, 18, 21, 1008, 17, 5, 22, 99, 0, 0, 0, 0, 0, 0];
+        let mut machine = SymbolicMachine::make(&prog);
+        let a = machine.symbolize(17);
+        let b = machine.symbolize(18);
+        assert_eq!(machine.continue_program(100), SymbolicEnd::EndCode);
+
+        let (sym_a, sym_b) = (Expr::Symbol(a), Expr::Symbol(b));
+        assert_eq!(machine.memory[19], Expr::Add(Box::new(sym_a.clone()), Box::new(sym_b.clone())));
+        assert_eq!(machine.memory[20], Expr::Mul(Box::new(sym_a.clone()), Box::new(Expr::Const(3))));
+        assert_eq!(machine.memory[21], Expr::LessThan(Box::new(sym_a.clone()), Box::new(sym_b.clone())));
+        assert_eq!(machine.memory[22], Expr::Equals(Box::new(sym_a.clone()), Box::new(Expr::Const(5))));
+        assert_eq!(machine.memory[21].eval(&vec![2, 9]), 1);
+        assert_eq!(machine.memory[22].substitute(a, 5), Expr::Const(1));
+    }
+
+    #[test]
+    fn folds_constants() {
+        assert_eq!(Expr::add(Expr::Const(2), Expr::Const(3)), Expr::Const(5));
+        assert_eq!(Expr::mul(Expr::Symbol(0), Expr::Const(0)), Expr::Const(0));
+        assert_eq!(Expr::mul(Expr::Const(1), Expr::Symbol(0)), Expr::Symbol(0));
+        assert_eq!(Expr::equals(Expr::Symbol(1), Expr::Symbol(1)), Expr::Const(1));
+        assert_eq!(Expr::less_than(Expr::Symbol(1), Expr::Symbol(1)), Expr::Const(0));
+    }
+
+    #[test]
+    fn linear_within_domains() {
+        // 100 * noun + verb + 4
+        let expr = Expr::add(Expr::add(Expr::mul(Expr::Const(100), Expr::Symbol(0)), Expr::Symbol(1)), Expr::Const(4));
+        let lin = expr.to_linear().unwrap();
+        assert_eq!((lin.constant, lin.coeffs.clone().into_iter().collect::<Vec<(usize, i64)>>()), (4, vec![(0, 100), (1, 1)]));
+
+        let mut values = vec![0, 0];
+        assert!(solve_linear(&lin, &domains(&[0..=99, 0..=99]), 1207, &mut values));
+        assert_eq!(values, vec![12, 3]);
+        assert!(!solve_linear(&lin, &domains(&[0..=99, 0..=99]), 10004, &mut values));
+        assert!(!solve_linear(&lin, &domains(&[0..=9, 0..=99]), 1207, &mut values));
+        assert!(!solve_linear(&lin, &domains(&[0..=99, 0..=2]), 1207, &mut values));
+    }
+
+    #[test]
+    fn non_linear_fallback() {
+        // a * b + (a < b), not linear so symbols get fixed one by one
+        let expr = Expr::add(Expr::mul(Expr::Symbol(0), Expr::Symbol(1)), Expr::less_than(Expr::Symbol(0), Expr::Symbol(1)));
+        assert!(expr.to_linear().is_none());
+        let values = solve(&expr, &domains(&[0..=20, 0..=20]), 36).unwrap();
+        assert_eq!(expr.eval(&values), 36);
+        assert_eq!(values, vec![5, 7]);
+        assert_eq!(solve(&expr, &domains(&[0..=20, 0..=20]), 1000), Option::None);
+    }
+
+    #[test]
+    fn day_02_noun_and_verb() {
+        // Like day 2 the noun and verb are addresses, mem[0] = mem[noun] * mem[11] + mem[verb]
+        let prog = vec![2, 0, 11, 13, 1, 13, 0, 0, 99, 7, 13, 100, 42, 0];
+        let target = 100 * 13 + 42;
+        let mut machine = SymbolicMachine::make(&prog);
+        machine.symbolize(1);
+        machine.symbolize(6);
+        let values = solve_machine(&machine, &domains(&[0..=12, 0..=12]), 0, target, 1000, 1).unwrap();
+
+        let brute_force = (0..=12).flat_map(|noun| (0..=12).map(move |verb| (noun, verb))).find(|(noun, verb)| {
+            let mut mem = prog.clone();
+            mem[1] = *noun;
+            mem[6] = *verb;
+            intcomputer::run_program(&mem, &vec![]).memory[0] == target
+        }).unwrap();
+        assert_eq!(100 * values[0] + values[1], 100 * brute_force.0 + brute_force.1);
+        assert_eq!(100 * values[0] + values[1], 312);
+    }
+
+    #[test]
+    fn forks_on_symbolic_jumps() {
+        // Jumps over the store unless mem[9] is 0, mem[10] = 7 is only written in that case
+        let prog = vec![1005, 9, 7, 1101, 3, 4, 10, 99, 0, 0, 0];
+        let mut machine = SymbolicMachine::make(&prog);
+        machine.symbolize(9);
+        assert_eq!(machine.clone().continue_program(100), SymbolicEnd::SymbolicJump(Expr::Symbol(0)));
+        assert_eq!(solve_machine(&machine, &domains(&[0..=5]), 10, 7, 100, 10), Option::from(vec![0]));
+        assert_eq!(solve_machine(&machine, &domains(&[1..=5]), 10, 0, 100, 10), Option::from(vec![1]));
+        assert_eq!(solve_machine(&machine, &domains(&[0..=MAX_FORKS]), 10, 7, 100, 10), Option::None);
+        // The blocked run and the fork for 1 are needed to find the solution
+        assert_eq!(solve_machine(&machine, &domains(&[1..=5]), 10, 0, 100, 1), Option::None);
+        assert_eq!(solve_machine(&machine, &domains(&[1..=5]), 10, 0, 100, 2), Option::from(vec![1]));
+    }
+}
@@ -1,7 +1,39 @@
 extern crate colored;
 
+use std::env;
+use std::io::{self, IsTerminal};
 use self::colored::*;
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never
+}
+
+impl ColorMode {
+    pub fn from(mode: &str) -> Option<Self> {
+        return match mode {
+            "auto" => Option::from(ColorMode::Auto),
+            "always" => Option::from(ColorMode::Always),
+            "never" => Option::from(ColorMode::Never),
+            _ => Option::None
+        };
+    }
+}
+
+/**
+ * Enable or disable colored output, auto only colors a terminal and honors NO_COLOR
+ */
+pub fn set_color_mode(mode: ColorMode) {
+    let enabled = match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => env::var_os("NO_COLOR").map_or(true, |val| val.is_empty()) && io::stdout().is_terminal()
+    };
+    control::set_override(enabled);
+}
+
 pub fn print_title(day: u8, title: &str){
     for _i in 0..3 {
         print!("{}", "~".white());
@@ -20,4 +52,4 @@ pub fn print_title(day: u8, title: &str){
         print!("{}", "~".white());
     }
     println!("");
-}
\ No newline at end of file
+}
@@ -0,0 +1,305 @@
+use std::vec::Vec;
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use num::{BigInt, FromPrimitive, Integer, Signed, ToPrimitive, Zero};
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+use crate::report::Report;
+
+#[derive(Default)]
+pub struct Puzzle {
+    masses: Vec<BigInt>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MassError {
+    line: usize,
+    text: String,
+    reason: &'static str
+}
+
+impl fmt::Display for MassError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "Invalid mass \"{}\" at line {}: {}", self.text, self.line, self.reason);
+    }
+}
+
+impl Error for MassError {}
+
+/**
+ * Masses are whole numbers of any size, leading and trailing whitespace is ignored
+ */
+fn parse_mass(text: &str, line: usize) -> Result<BigInt, MassError> {
+    let error = |reason| MassError {
+        line: line,
+        text: text.to_string(),
+        reason: reason
+    };
+
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err(error("empty line"));
+    }
+    let mass = trimmed.parse::<BigInt>().map_err(|_e| error("not a number"))?;
+    if mass.is_negative() {
+        return Err(error("mass can't be negative"));
+    }
+    return Ok(mass);
+}
+
+fn read_puzzle_input(input: &str) -> Result<Vec<BigInt>, MassError> {
+    let mut masses = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        masses.push(parse_mass(line, i + 1)?);
+    }
+
+    return Ok(masses);
+}
+
+fn fuel_from_mass<T>(mass: &T) -> T where T: Integer + Signed + Clone + FromPrimitive {
+    return mass.clone() / T::from_u8(3).unwrap() - T::from_u8(2).unwrap();
+}
+
+/**
+ * Fuel needed for one module, the first entry of the chain is the fuel for the module mass
+ * and every following entry the fuel needed to carry the previous one
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleFuel<T> {
+    pub mass: T,
+    pub chain: Vec<T>,
+}
+
+impl<T> ModuleFuel<T> where T: Integer + Signed + Clone + FromPrimitive {
+    pub fn base(self: &Self) -> T {
+        return self.chain[0].clone();
+    }
+
+    pub fn total(self: &Self) -> T {
+        return self.chain.iter().fold(T::zero(), |sum, fuel| sum + fuel.clone());
+    }
+}
+
+pub fn fuel_model<T>(mass: &T) -> ModuleFuel<T> where T: Integer + Signed + Clone + FromPrimitive {
+    let mut chain = vec![fuel_from_mass(mass)];
+    loop {
+        let added = fuel_from_mass(chain.last().unwrap());
+        if !added.is_positive() {
+            break;
+        }
+        chain.push(added);
+    }
+
+    return ModuleFuel {
+        mass: mass.clone(),
+        chain: chain
+    };
+}
+
+/**
+ * Fuel for the module mass and the total with the fuel for fuel, like fuel_model without keeping the chain
+ */
+fn fuel_sums<T>(mass: &T) -> (T, T) where T: Integer + Signed + Clone + FromPrimitive {
+    let base = fuel_from_mass(mass);
+    let mut total = base.clone();
+    let mut added = fuel_from_mass(&base);
+    while added.is_positive() {
+        total = total + added.clone();
+        added = fuel_from_mass(&added);
+    }
+    return (base, total);
+}
+
+/**
+ * Fuel of both parts summed up module by module. Masses that fit into an i64 are
+ * calculated with plain integers, an i128 sum can't overflow for any realistic module count.
+ */
+#[derive(Default, Debug)]
+pub struct FuelTotals {
+    pub modules: usize,
+    small_fuel: i128,
+    small_total: i128,
+    big_fuel: BigInt,
+    big_total: BigInt,
+}
+
+impl FuelTotals {
+    pub fn add(self: &mut Self, mass: &BigInt) {
+        match mass.to_i64() {
+            Some(small) => self.add_small(small),
+            None => {
+                let (fuel, total) = fuel_sums(mass);
+                self.big_fuel += fuel;
+                self.big_total += total;
+                self.modules += 1;
+            }
+        }
+    }
+
+    fn add_small(self: &mut Self, mass: i64) {
+        let (fuel, total) = fuel_sums(&mass);
+        self.small_fuel += fuel as i128;
+        self.small_total += total as i128;
+        self.modules += 1;
+    }
+
+    pub fn fuel(self: &Self) -> BigInt {
+        return BigInt::from(self.small_fuel) + &self.big_fuel;
+    }
+
+    pub fn fuel_total(self: &Self) -> BigInt {
+        return BigInt::from(self.small_total) + &self.big_total;
+    }
+}
+
+/**
+ * Sum up the fuel line by line without keeping the masses in memory
+ */
+pub fn stream_totals<R: BufRead + ?Sized>(reader: &mut R) -> Result<FuelTotals, Box<dyn Error>> {
+    let mut totals = FuelTotals::default();
+    let mut line = String::new();
+    let mut line_nr = 0;
+    while reader.read_line(&mut line)? > 0 {
+        line_nr += 1;
+        match line.trim().parse::<i64>() {
+            Ok(mass) if mass >= 0 => totals.add_small(mass),
+            _ => totals.add(&parse_mass(line.trim_end_matches(|c| c == '\n' || c == '\r'), line_nr)?)
+        }
+        line.clear();
+    }
+    return Ok(totals);
+}
+
+/**
+ * One row per module sorted by the total fuel it needs, the modules are numbered like the input lines
+ */
+fn fuel_report(masses: &Vec<BigInt>) -> Report {
+    let mut modules: Vec<(usize, ModuleFuel<BigInt>)> = masses.iter().enumerate().map(|(i, mass)| (i + 1, fuel_model(mass))).collect();
+    modules.sort_by_key(|(line, module)| (-module.total(), *line));
+    let total = modules.iter().fold(BigInt::zero(), |sum, (_line, module)| sum + module.total());
+
+    let mut report = Report::new(&["module", "mass", "fuel", "fuel for fuel", "total", "share"]);
+    for (line, module) in &modules {
+        let chain: Vec<String> = module.chain[1..].iter().map(|fuel| fuel.to_string()).collect();
+        let share = module.total().to_f64().unwrap_or(0.0) * 100.0 / total.to_f64().unwrap_or(1.0);
+        report.add_row(vec![
+            line.to_string(),
+            module.mass.to_string(),
+            module.base().to_string(),
+            chain.join("+"),
+            module.total().to_string(),
+            format!("{:.2}%", share)
+        ]);
+    }
+    return report;
+}
+
+impl Puzzle {
+    fn totals(self: &Self) -> FuelTotals {
+        let mut totals = FuelTotals::default();
+        for mass in &self.masses {
+            totals.add(mass);
+        }
+        return totals;
+    }
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "The Tyranny of the Rocket Equation";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_01/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.masses = read_puzzle_input(input.text()?)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return Answer::from(self.totals().fuel());
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return Answer::from(self.totals().fuel_total());
+    }
+
+    fn report(self: &Self) -> Option<Report> {
+        return Option::from(fuel_report(&self.masses));
+    }
+
+    fn stream(self: &Self, reader: &mut dyn BufRead) -> Option<Result<(Answer, Answer), Box<dyn Error>>> {
+        return Option::from(stream_totals(reader).map(|totals| (Answer::from(totals.fuel()), Answer::from(totals.fuel_total()))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn fuel_from_mass_examples() {
+        assert_eq!(fuel_from_mass(&12), 2);
+        assert_eq!(fuel_from_mass(&14), 2);
+        assert_eq!(fuel_from_mass(&1969), 654);
+        assert_eq!(fuel_from_mass(&100756), 33583);
+    }
+
+    #[test]
+    fn fuel_for_fuel_examples() {
+        let puzzle = Puzzle { masses: read_puzzle_input("14\n1969\n100756").unwrap() };
+        assert_eq!(puzzle.part1(), Answer::from(2 + 654 + 33583));
+        assert_eq!(puzzle.part2(), Answer::from(2 + 966 + 50346));
+    }
+
+    #[test]
+    fn fuel_model_chain() {
+        let module = fuel_model(&1969);
+        assert_eq!(module.chain, vec![654, 216, 70, 21, 5]);
+        assert_eq!(module.base(), 654);
+        assert_eq!(module.total(), 966);
+        assert_eq!(fuel_model(&14).chain, vec![2]);
+        for mass in [1, 14, 1969, 100756] {
+            assert_eq!(fuel_sums(&mass), (fuel_model(&mass).base(), fuel_model(&mass).total()));
+        }
+    }
+
+    #[test]
+    fn report_sorted_by_total() {
+        let report = fuel_report(&read_puzzle_input("14\n100756\n1969").unwrap());
+        assert_eq!(report.rows.iter().map(|row| row[0].as_str()).collect::<Vec<&str>>(), vec!["2", "3", "1"]);
+        assert_eq!(report.rows[1], vec!["3", "1969", "654", "216+70+21+5", "966", "1.88%"]);
+    }
+
+    #[test]
+    fn big_masses() {
+        let puzzle = Puzzle { masses: read_puzzle_input("18446744073709551615\n300000000000000000000000000000").unwrap() };
+        assert_eq!(puzzle.part1(), Answer::from("100000000006148914691236517201"));
+        assert_eq!(fuel_model(&BigInt::from(u64::MAX)).base(), BigInt::from(6148914691236517203u64));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(read_puzzle_input("12\n\n14").unwrap_err().to_string(), "Invalid mass \"\" at line 2: empty line");
+        assert_eq!(read_puzzle_input("12\n14\n1x").unwrap_err().to_string(), "Invalid mass \"1x\" at line 3: not a number");
+        assert_eq!(read_puzzle_input("-5").unwrap_err().line, 1);
+        assert_eq!(read_puzzle_input(" 12 \r\n14").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn streaming_matches_parts() {
+        let text = "14\n1969\n100756\n18446744073709551615\n";
+        let puzzle = Puzzle { masses: read_puzzle_input(text).unwrap() };
+        let totals = stream_totals(&mut Cursor::new(text)).unwrap();
+        assert_eq!(totals.modules, 4);
+        assert_eq!(Answer::from(totals.fuel()), puzzle.part1());
+        assert_eq!(Answer::from(totals.fuel_total()), puzzle.part2());
+        assert_eq!(stream_totals(&mut Cursor::new("14\nabc\n")).unwrap_err().to_string(), "Invalid mass \"abc\" at line 2: not a number");
+    }
+}
@@ -0,0 +1,198 @@
+use std::vec::Vec;
+use std::error::Error;
+use crate::goalseek::{self, Variable};
+use crate::loader;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+use crate::report::Report;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EndReason {
+    EndCode,
+    CodeUnknown,
+    EndProgram,
+    AddressInvalid
+}
+
+#[derive(Debug)]
+struct ProgramResult {
+    memory: Vec<i32>,
+    code: EndReason,
+    counter: usize,
+}
+
+const NOUN_ADDR: usize = 1;
+const VERB_ADDR: usize = 2;
+const OUTPUT_ADDR: usize = 0;
+const TARGET_OUTPUT: i64 = 19690720;
+
+#[derive(Default)]
+pub struct Puzzle {
+    mem: Vec<i32>
+}
+
+/**
+ * The three addresses of an add or multiply, None if one of them is outside of the program
+ */
+fn instruction_addrs(prog: &Vec<i32>, counter: usize) -> Option<[usize; 3]> {
+    let mut addrs = [0; 3];
+    for i in 0..3 {
+        let addr = *prog.get(counter + 1 + i)?;
+        if addr < 0 || addr as usize >= prog.len() {
+            return Option::None;
+        }
+        addrs[i] = addr as usize;
+    }
+    return Option::from(addrs);
+}
+
+fn run_program(mut prog: Vec<i32>) -> ProgramResult{
+    let mut counter = 0;
+    let mut running = true;
+    let mut end_reason = EndReason::EndProgram;
+
+    while running && counter < prog.len(){
+        match prog[counter] {
+            code @ (1 | 2) => {
+                match instruction_addrs(&prog, counter) {
+                    Some([a, b, c]) => {
+                        prog[c] = if code == 1 { prog[b] + prog[a] } else { prog[b] * prog[a] };
+                        counter += 4
+                    },
+                    None => {
+                        end_reason = EndReason::AddressInvalid;
+                        running = false;
+                    }
+                }
+            },
+            99 => {
+                end_reason = EndReason::EndCode;
+                running = false;
+            },
+            _ => {
+                end_reason = EndReason::CodeUnknown;
+                running = false;
+            }
+        }
+    }
+
+    return ProgramResult {
+        memory: prog,
+        code: end_reason,
+        counter: counter
+    }
+}
+
+/**
+ * Initial and final value of every cell the program changed, together with the reason it stopped
+ */
+fn memory_diff(initial: &Vec<i32>, result: &ProgramResult) -> Report {
+    let mut report = Report::new(&["address", "initial", "final", "change"]);
+    for (addr, (old, new)) in initial.iter().zip(result.memory.iter()).enumerate() {
+        if old != new {
+            report.add_row(vec![addr.to_string(), old.to_string(), new.to_string(), format!("{:+}", *new as i64 - *old as i64)]);
+        }
+    }
+    report.add_note(format!("{} of {} cells changed, stopped with {:?} at counter {}", report.rows.len(), initial.len(), result.code, result.counter));
+    return report;
+}
+
+/**
+ * The alarm writes the noun and verb into the program, so it needs the cells up to the verb address
+ */
+fn check_program(mem: Vec<i32>) -> Result<Vec<i32>, String> {
+    if mem.len() <= VERB_ADDR {
+        return Err(format!("The program needs at least {} cells for the noun and verb, it has {}", VERB_ADDR + 1, mem.len()));
+    }
+    return Ok(mem);
+}
+
+impl Puzzle {
+    /**
+     * Program in the state before the alarm, the 1202 of the title is noun 12 and verb 2
+     */
+    fn alarm_program(self: &Self) -> Vec<i32> {
+        let mut mem = self.mem.clone();
+        mem[NOUN_ADDR] = 12;
+        mem[VERB_ADDR] = 2;
+        return mem;
+    }
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "1202 Program Alarm ";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_02/program.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.mem = check_program(loader::load_program_i32(input)?)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        let result_1 = run_program(self.alarm_program());
+        if result_1.code != EndReason::EndCode {
+            return Answer::from(format!("The program stopped with {:?} at counter {}", result_1.code, result_1.counter).as_str());
+        }
+        return Answer::from(result_1.memory[OUTPUT_ADDR]);
+    }
+
+    fn part2(self: &Self) -> Answer {
+        let variables = vec![
+            Variable { addr: NOUN_ADDR, range: 0..=99 },
+            Variable { addr: VERB_ADDR, range: 0..=99 }
+        ];
+        let prog: Vec<i64> = self.mem.iter().map(|val| *val as i64).collect();
+        return match goalseek::seek(&prog, &variables, OUTPUT_ADDR, TARGET_OUTPUT) {
+            Some(solution) => Answer::from(solution.values[0] * 100 + solution.values[1]),
+            None => Answer::from("No solution found")
+        }
+    }
+
+    fn report(self: &Self) -> Option<Report> {
+        let initial = self.alarm_program();
+        return Option::from(memory_diff(&initial, &run_program(initial.clone())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_program_examples() {
+        assert_eq!(run_program(vec![1,9,10,3,2,3,11,0,99,30,40,50]).memory, vec![3500,9,10,70,2,3,11,0,99,30,40,50]);
+        assert_eq!(run_program(vec![1,0,0,0,99]).memory, vec![2,0,0,0,99]);
+        assert_eq!(run_program(vec![2,3,0,3,99]).memory, vec![2,3,0,6,99]);
+        assert_eq!(run_program(vec![2,4,4,5,99,0]).memory, vec![2,4,4,5,99,9801]);
+        assert_eq!(run_program(vec![1,1,1,4,99,5,6,0,99]).memory, vec![30,1,1,4,2,5,6,0,99]);
+    }
+
+    #[test]
+    fn memory_diff_example() {
+        let initial = vec![1,9,10,3,2,3,11,0,99,30,40,50];
+        let result = run_program(initial.clone());
+        assert_eq!(result.code, EndReason::EndCode);
+        assert_eq!(result.counter, 8);
+
+        let report = memory_diff(&initial, &result);
+        assert_eq!(report.rows, vec![vec!["0", "1", "3500", "+3499"], vec!["3", "3", "70", "+67"]]);
+        assert_eq!(report.notes, vec!["2 of 12 cells changed, stopped with EndCode at counter 8"]);
+        assert_eq!(run_program(vec![1,0,0,0,42]).code, EndReason::CodeUnknown);
+    }
+
+    #[test]
+    fn short_programs() {
+        assert_eq!(check_program(vec![99]).unwrap_err(), "The program needs at least 3 cells for the noun and verb, it has 1");
+        assert_eq!(run_program(vec![1,0,0]).code, EndReason::AddressInvalid);
+        assert_eq!(run_program(vec![1,0,-1,0,99]).code, EndReason::AddressInvalid);
+
+        let puzzle = Puzzle { mem: check_program(vec![1,0,0,3]).unwrap() };
+        assert_eq!(puzzle.part1(), Answer::from("The program stopped with AddressInvalid at counter 0"));
+    }
+}
@@ -1,65 +1,135 @@
-extern crate regex;
-
-
+use std::error::Error;
+use std::fmt;
 use std::vec::Vec;
 use std::string::String;
-use self::regex::Regex;
+use crate::grid::Direction;
 
-lazy_static! {
-    static ref CABELE_REGEX : Regex = Regex::new(r"(?m)([LRUD])(\d*)").unwrap();
+#[derive(Clone, Debug, PartialEq)]
+pub struct CableStep {
+    pub direction: Direction,
+    pub steps: i32
 }
 
-#[derive(Eq, PartialEq, Clone, Debug, Copy)]
-pub enum Direction {
-    UP,
-    DOWN,
-    RIGHT,
-    LEFT
+/**
+ * Invalid step in the input, wire and token are counted from 1 like lines and steps in the text
+ */
+#[derive(Debug, PartialEq)]
+pub struct StepError {
+    pub wire: usize,
+    pub token: usize,
+    pub text: String,
+    pub reason: String
 }
 
-#[derive(Eq, PartialEq, Clone, Debug, Copy)]
-pub enum Orientaition {
-    Horizontal,
-    Vertical
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "Invalid step \"{}\" in wire {}, step {}: {}", self.text, self.wire, self.token, self.reason);
+    }
 }
 
-#[derive(Clone, Debug)]
-pub struct CableStep {
-    pub direction: Direction,
-    pub steps: i32
+impl Error for StepError {}
+
+fn direction_letter(direction: Direction) -> char {
+    return match direction {
+        Direction::UP => 'U',
+        Direction::RIGHT => 'R',
+        Direction::DOWN => 'D',
+        Direction::LEFT => 'L'
+    };
 }
 
-pub fn parse_step(step: String) -> CableStep {
-    let caps = CABELE_REGEX.captures(&step).unwrap();
-    let steps = caps.get(2).map_or(0, |m| m.as_str().parse::<i32>().unwrap());
-    let mut direction = Direction::DOWN;
-    match caps.get(1).unwrap().as_str().as_ref() {
-        "L" => direction = Direction::LEFT,
-        "R" => direction = Direction::RIGHT,
-        "U" => direction = Direction::UP,
-        "D" => direction = Direction::DOWN,
-        _ => {}
+impl fmt::Display for CableStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}{}", direction_letter(self.direction), self.steps);
+    }
+}
+
+/**
+ * Parse a step like "R75", the direction may be lowercase and whitespace around the parts is ignored
+ */
+pub fn parse_step(step: &str) -> Result<CableStep, String> {
+    let text = step.trim();
+    let mut chars = text.chars();
+    let direction = match chars.next().map(|c| c.to_ascii_uppercase()) {
+        Some('L') => Direction::LEFT,
+        Some('R') => Direction::RIGHT,
+        Some('U') => Direction::UP,
+        Some('D') => Direction::DOWN,
+        Some(c) => return Err(format!("unknown direction '{}'", c)),
+        None => return Err(String::from("empty step"))
+    };
+
+    let length = chars.as_str().trim();
+    if length.is_empty() {
+        return Err(String::from("missing length"));
     }
+    let steps = length.parse::<i32>().ok().filter(|steps| *steps >= 0).ok_or(format!("invalid length \"{}\"", length))?;
 
-    return CableStep {
+    return Ok(CableStep {
         steps: steps,
         direction: direction
-    }
+    });
 }
 
-pub fn parse_steps(steps: Vec<String>) -> Vec<CableStep> {
+pub fn parse_steps(line: &str, wire: usize) -> Result<Vec<CableStep>, StepError> {
     let mut step_list = Vec::new();
-    for step in steps {
-        step_list.push(parse_step(step));
+    for (i, step) in line.split(",").enumerate() {
+        step_list.push(parse_step(step).map_err(|reason| StepError {
+            wire: wire,
+            token: i + 1,
+            text: step.trim().to_string(),
+            reason: reason
+        })?);
+    }
+    return Ok(step_list);
+}
+
+/**
+ * One wire per line, blank lines are skipped
+ */
+pub fn parse_wires(text: &str) -> Result<Vec<Vec<CableStep>>, StepError> {
+    let mut wires = Vec::new();
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        wires.push(parse_steps(line, wires.len() + 1)?);
     }
-    return step_list;
+    return Ok(wires);
 }
 
-pub fn get_orientation(dir: Direction) -> Orientaition{
-    match dir {
-        Direction::LEFT => return Orientaition::Horizontal,
-        Direction::RIGHT => return Orientaition::Horizontal,
-        Direction::UP => return Orientaition::Vertical,
-        Direction::DOWN => return Orientaition::Vertical,
+/**
+ * Write the steps in the input format like "R75,D30"
+ */
+pub fn format_steps(steps: &[CableStep]) -> String {
+    return steps.iter().map(|step| step.to_string()).collect::<Vec<String>>().join(",");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lenient_steps() {
+        assert_eq!(parse_step(" r75 "), Ok(CableStep { direction: Direction::RIGHT, steps: 75 }));
+        assert_eq!(parse_step("u 3"), Ok(CableStep { direction: Direction::UP, steps: 3 }));
+        assert_eq!(parse_steps("R8, u5,\tL5 ,d3", 1).unwrap().len(), 4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn step_errors() {
+        assert_eq!(parse_step("X12"), Err(String::from("unknown direction 'X'")));
+        assert_eq!(parse_step("R"), Err(String::from("missing length")));
+        assert_eq!(parse_step("D-4"), Err(String::from("invalid length \"-4\"")));
+        assert_eq!(parse_step(""), Err(String::from("empty step")));
+
+        let err = parse_wires("R8,U5\n\nU7,X12,D4").unwrap_err();
+        assert_eq!((err.wire, err.token), (2, 2));
+        assert_eq!(err.to_string(), "Invalid step \"X12\" in wire 2, step 2: unknown direction 'X'");
+        assert_eq!(parse_wires("R8,").unwrap_err().reason, "empty step");
+    }
+
+    #[test]
+    fn format_round_trip() {
+        let text = "R75,D30,R83,U83,L12,D49,R71,U7,L72";
+        assert_eq!(format_steps(&parse_steps(text, 1).unwrap()), text);
+        assert_eq!(format_steps(&parse_steps("r 1, d2", 1).unwrap()), "R1,D2");
+    }
+}
@@ -0,0 +1,314 @@
+mod cables;
+mod sweep;
+mod svg;
+
+use std::collections::{BTreeMap, HashMap};
+use std::string::String;
+use std::vec::Vec;
+use std::error::Error;
+use std::hint;
+use crate::answer::Answer;
+use crate::grid::{Direction, Point};
+use crate::day::{Benchmark, Day};
+use crate::input::Input;
+use crate::report::Report;
+
+/**
+ * Segment of a wire, steps is the length of the wire before the start of the segment
+ */
+#[derive(Eq, PartialEq, Clone, Debug, Copy)]
+pub struct Line2D {
+    start: Point<i32>,
+    dir : Direction,
+    len: i32,
+    steps: i32
+}
+
+/**
+ * Point where two or more wires cross, steps holds for every crossing wire (by index)
+ * the steps it needs to reach the point the first time
+ */
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Intersection {
+    pub pos: Point<i32>,
+    pub steps: BTreeMap<usize, i32>,
+}
+
+impl Intersection {
+    pub fn distance(self: &Self) -> i32 {
+        return self.pos.manhattan(&Point::from(0, 0));
+    }
+
+    pub fn combined_steps(self: &Self) -> i32 {
+        return self.steps.values().sum();
+    }
+
+    pub fn wires(self: &Self) -> Vec<usize> {
+        return self.steps.keys().copied().collect();
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    cables: Vec<Vec<cables::CableStep>>
+}
+
+fn read_puzzle_input(input: &str) -> Result<Vec<Vec<cables::CableStep>>, cables::StepError> {
+    return cables::parse_wires(input);
+}
+
+fn trace_cable(cable : & [cables::CableStep]) -> Vec<Line2D>{
+    let mut current = Point::from(0, 0);
+    let mut walked = 0;
+    let mut lines : Vec<Line2D> = Vec::new();
+    for step in cable {
+        lines.push(Line2D {
+            start: current,
+            dir: step.direction,
+            len: step.steps,
+            steps: walked
+        });
+        current = step.direction.move_point(current, step.steps);
+        walked += step.steps;
+    }
+    return lines;
+}
+
+/**
+ * Check if the start of b is on the range covered by a, the start of a is included and the end excluded
+ */
+fn check_possible_intersection(a: Line2D, b: Line2D) -> bool {
+    let end = a.dir.move_point(a.start, a.len);
+    let (start, end, pos) = if a.dir.is_horizontal() { (a.start.x, end.x, b.start.x) } else { (a.start.y, end.y, b.start.y) };
+    return (start <= pos && pos < end) || (end < pos && pos <= start);
+}
+
+fn cross_lines(a: Line2D, b: Line2D) -> Option<Point<i32>> {
+    if a.dir.is_horizontal() == b.dir.is_horizontal() {
+        return Option::None;
+    }
+
+    if !check_possible_intersection(a, b) || !check_possible_intersection(b, a){
+        return Option::None;
+    }
+
+    if a.dir.is_horizontal() {
+        return Option::from(Point::from(b.start.x, a.start.y));
+    }
+    else {
+        return Option::from(Point::from(a.start.x, b.start.y));
+    }
+}
+
+fn calc_steps(cur_line: Line2D, pt: Point<i32>) -> i32 {
+    if cur_line.dir.is_horizontal() {
+        return i32::abs(cur_line.start.x - pt.x)
+    }
+    else {
+        return i32::abs(cur_line.start.y - pt.y)
+    }
+}
+
+
+fn trace_cables(cables : &Vec<Vec<cables::CableStep>>) -> Vec<Vec<Line2D>> {
+    return cables.iter().map(|cable| trace_cable(cable)).collect();
+}
+
+/**
+ * All points except the origin where at least two different wires meet, sorted by distance to the origin.
+ * Wires running on top of each other meet on every point they share.
+ */
+fn find_intersections(wires: &Vec<Vec<Line2D>>) -> Vec<Intersection> {
+    let mut crossings : HashMap<Point<i32>, BTreeMap<usize, i32>> = HashMap::new();
+    for contact in sweep::find_contacts(wires) {
+        let len = contact.from.manhattan(&contact.to);
+        let dir = if contact.a.line.dir.move_point(contact.from, len) == contact.to { contact.a.line.dir } else { contact.a.line.dir.reverse() };
+        for i in 0..=len {
+            let pt = dir.move_point(contact.from, i);
+            if pt == Point::from(0, 0) {
+                continue;
+            }
+
+            let steps = crossings.entry(pt).or_insert(BTreeMap::new());
+            for wl in [contact.a, contact.b].iter() {
+                let walked = wl.line.steps + calc_steps(wl.line, pt);
+                let first = steps.entry(wl.wire).or_insert(walked);
+                *first = i32::min(*first, walked);
+            }
+        }
+    }
+
+    let mut intersections : Vec<Intersection> = crossings.into_iter().map(|(pos, steps)| Intersection {
+        pos: pos,
+        steps: steps
+    }).collect();
+    intersections.sort_by_key(|inter| (inter.distance(), inter.pos));
+    return intersections;
+}
+
+/**
+ * Smallest distance and fewest combined steps of all intersections, None if the wires don't intersect
+ */
+fn solve_puzzle(cables : &Vec<Vec<cables::CableStep>>) -> Option<(i32, i32)> {
+    let intersections = find_intersections(&trace_cables(cables));
+    let smallest_dist = intersections.iter().map(|inter| inter.distance()).min()?;
+    let smallest_path = intersections.iter().map(|inter| inter.combined_steps()).min()?;
+    return Option::from((smallest_dist, smallest_path));
+}
+
+fn answer(solution: Option<i32>) -> Answer {
+    return match solution {
+        Some(val) => Answer::from(val),
+        None => Answer::from("The wires don't intersect")
+    };
+}
+
+/**
+ * Every intersection with the wires crossing there and their steps, sorted by distance
+ */
+fn intersection_report(intersections: &Vec<Intersection>) -> Report {
+    let mut report = Report::new(&["x", "y", "distance", "wires", "steps", "combined"]);
+    for inter in intersections {
+        let wires: Vec<String> = inter.wires().iter().map(|wire| (wire + 1).to_string()).collect();
+        let steps: Vec<String> = inter.steps.values().map(|steps| steps.to_string()).collect();
+        report.add_row(vec![
+            inter.pos.x.to_string(),
+            inter.pos.y.to_string(),
+            inter.distance().to_string(),
+            wires.join("+"),
+            steps.join("+"),
+            inter.combined_steps().to_string()
+        ]);
+    }
+
+    let multi = intersections.iter().filter(|inter| inter.steps.len() >= 3).count();
+    report.add_note(format!("{} intersections, {} of them with three or more wires", intersections.len(), multi));
+    return report;
+}
+
+const TOOL_USAGE: &str = "Usage: aoc tool 3 format
+Prints every wire of the input as normalized steps like R75,D30
+";
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Crossed Wires";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_03/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.cables = read_puzzle_input(input.text()?)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return answer(solve_puzzle(&self.cables).map(|solution| solution.0));
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return answer(solve_puzzle(&self.cables).map(|solution| solution.1));
+    }
+
+    fn report(self: &Self) -> Option<Report> {
+        return Option::from(intersection_report(&find_intersections(&trace_cables(&self.cables))));
+    }
+
+    fn render_svg(self: &Self) -> Option<String> {
+        let wires = trace_cables(&self.cables);
+        return Option::from(svg::wires_svg(&wires, &find_intersections(&wires)));
+    }
+
+    fn benchmarks(self: &Self) -> Vec<Benchmark> {
+        // Two random wires with 100000 segments each, much longer than the puzzle input
+        let wires = sweep::random_wires(0x9E3779B97F4A7C15, 2, 100000, 1000);
+        return vec![Benchmark {
+            name: "sweep_100k",
+            run: Box::new(move || { hint::black_box(sweep::find_contacts(&wires)); })
+        }];
+    }
+
+    fn tool(self: &mut Self, args: &[String], input: &dyn Fn() -> Result<Input, String>) -> Option<Result<String, String>> {
+        if args.len() != 1 || args[0] != "format" {
+            return Option::from(Err(String::from(TOOL_USAGE)));
+        }
+        return Option::from(self.load_input(input).map(|()| {
+            return self.cables.iter().map(|cable| format!("{}\n", cables::format_steps(cable))).collect();
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(input: &str) -> (i32, i32) {
+        return solve_puzzle(&read_puzzle_input(input).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn cross_lines_perpendicular() {
+        let a = Line2D { start: Point::from(0, 0), dir: Direction::RIGHT, len: 8, steps: 0 };
+        let b = Line2D { start: Point::from(3, -5), dir: Direction::DOWN, len: 10, steps: 0 };
+        assert_eq!(cross_lines(a, b), Option::from(Point::from(3, 0)));
+        assert_eq!(cross_lines(b, a), Option::from(Point::from(3, 0)));
+
+        let c = Line2D { start: Point::from(9, -5), dir: Direction::DOWN, len: 10, steps: 0 };
+        assert_eq!(cross_lines(a, c), Option::None);
+    }
+
+    #[test]
+    fn cross_lines_parallel() {
+        let a = Line2D { start: Point::from(0, 0), dir: Direction::RIGHT, len: 8, steps: 0 };
+        let b = Line2D { start: Point::from(2, 0), dir: Direction::LEFT, len: 5, steps: 0 };
+        assert_eq!(cross_lines(a, b), Option::None);
+    }
+
+    #[test]
+    fn solve_examples() {
+        assert_eq!(solve("R8,U5,L5,D3\nU7,R6,D4,L4"), (6, 30));
+        assert_eq!(solve("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"), (159, 610));
+        assert_eq!(solve("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"), (135, 410));
+    }
+
+    #[test]
+    fn no_intersection() {
+        for input in ["R8,U5,L5,D3", "U5,R3\nD5,L3"] {
+            let puzzle = Puzzle { cables: read_puzzle_input(input).unwrap() };
+            assert_eq!(puzzle.part1(), Answer::from("The wires don't intersect"));
+            assert_eq!(puzzle.part2(), Answer::from("The wires don't intersect"));
+        }
+    }
+
+    #[test]
+    fn three_wires() {
+        let wires = trace_cables(&read_puzzle_input("R8,U5,L5,D3\nU7,R6,D4,L4\nU3,R10").unwrap());
+        let intersections = find_intersections(&wires);
+        assert_eq!(intersections.iter().map(|inter| inter.pos).collect::<Vec<Point<i32>>>(),
+            vec![Point::from(0, -1), Point::from(0, -2), Point::from(0, -3), Point::from(3, -3), Point::from(4, -3),
+                 Point::from(5, -3), Point::from(6, -3), Point::from(6, -5), Point::from(8, -3)]);
+        assert_eq!(intersections[1].steps.values().copied().collect::<Vec<i32>>(), vec![2, 2]);
+        assert_eq!(intersections[2].steps.values().copied().collect::<Vec<i32>>(), vec![3, 3]);
+
+        let triple = &intersections[3];
+        assert_eq!(triple.wires(), vec![0, 1, 2]);
+        assert_eq!(triple.steps.values().copied().collect::<Vec<i32>>(), vec![20, 20, 6]);
+        assert_eq!(triple.combined_steps(), 46);
+        assert_eq!(intersections[5].wires(), vec![1, 2]);
+        assert_eq!(intersections[8].wires(), vec![0, 2]);
+
+        let report = intersection_report(&intersections);
+        assert_eq!(report.rows[3], vec!["3", "-3", "6", "1+2+3", "20+20+6", "46"]);
+        assert_eq!(report.notes, vec!["9 intersections, 1 of them with three or more wires"]);
+    }
+
+    #[test]
+    fn format_tool() {
+        let input = || Ok(Input { name: String::from("test"), data: b"r8, u5,L5,d 3\n\nU7,R6,D4,L4\n".to_vec() });
+        let mut puzzle = Puzzle::default();
+        assert_eq!(puzzle.tool(&[String::from("format")], &input), Option::from(Ok(String::from("R8,U5,L5,D3\nU7,R6,D4,L4\n"))));
+        assert!(puzzle.tool(&[String::from("draw")], &input).unwrap().is_err());
+    }
+}
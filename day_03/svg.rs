@@ -0,0 +1,88 @@
+use std::fmt::Write;
+use crate::grid::{BoundingBox, Point};
+use super::{Intersection, Line2D};
+
+const WIRE_COLORS: [&str; 6] = ["#1f77b4", "#ff7f0e", "#2ca02c", "#9467bd", "#8c564b", "#e377c2"];
+const CLOSEST_COLOR: &str = "#d62728";
+const SHORTEST_COLOR: &str = "#17becf";
+
+fn wire_points(lines: &Vec<Line2D>) -> Vec<Point<i32>> {
+    let mut points = vec![Point::from(0, 0)];
+    for line in lines {
+        points.push(line.dir.move_point(line.start, line.len));
+    }
+    return points;
+}
+
+fn marker(svg: &mut String, pos: Point<i32>, radius: f64, color: &str, label: Option<String>, font: f64) {
+    writeln!(svg, "  <circle cx=\"{}\" cy=\"{}\" r=\"{:.1}\" fill=\"{}\"/>", pos.x, pos.y, radius, color).unwrap();
+    if let Some(text) = label {
+        writeln!(svg, "  <text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.1}\" fill=\"{}\">{}</text>", pos.x as f64 + radius * 1.5, pos.y as f64 - radius * 1.5, font, color, text).unwrap();
+    }
+}
+
+/**
+ * Draw every wire in its own color together with the origin and all crossings, the intersection
+ * closest to the origin and the one with the fewest combined steps are highlighted and labeled
+ */
+pub fn wires_svg(wires: &Vec<Vec<Line2D>>, intersections: &Vec<Intersection>) -> String {
+    let paths: Vec<Vec<Point<i32>>> = wires.iter().map(wire_points).collect();
+    let bbox = BoundingBox::from_points(paths.iter().flatten()).unwrap_or(BoundingBox { min: Point::from(0, 0), max: Point::from(0, 0) });
+    let size = i32::max(bbox.max.x - bbox.min.x, bbox.max.y - bbox.min.y).max(10) as f64;
+    // Small drawings would round the stroke down to 0.0 and hide the wires
+    let stroke = f64::max(size / 800.0, 0.2);
+    let margin = size / 20.0;
+
+    let mut svg = String::new();
+    writeln!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{:.1} {:.1} {:.1} {:.1}\">",
+        bbox.min.x as f64 - margin, bbox.min.y as f64 - margin,
+        (bbox.max.x - bbox.min.x) as f64 + 2.0 * margin, (bbox.max.y - bbox.min.y) as f64 + 2.0 * margin).unwrap();
+    writeln!(svg, "  <rect x=\"{:.1}\" y=\"{:.1}\" width=\"100%\" height=\"100%\" fill=\"white\"/>", bbox.min.x as f64 - margin, bbox.min.y as f64 - margin).unwrap();
+
+    for (i, points) in paths.iter().enumerate() {
+        let coords: Vec<String> = points.iter().map(|pt| format!("{},{}", pt.x, pt.y)).collect();
+        writeln!(svg, "  <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{:.1}\"><title>wire {}</title></polyline>",
+            coords.join(" "), WIRE_COLORS[i % WIRE_COLORS.len()], stroke, i + 1).unwrap();
+    }
+
+    for inter in intersections {
+        marker(&mut svg, inter.pos, stroke * 2.0, "black", Option::None, 0.0);
+    }
+    marker(&mut svg, Point::from(0, 0), stroke * 4.0, "black", Option::from(String::from("origin")), size / 50.0);
+
+    let closest = intersections.iter().min_by_key(|inter| (inter.distance(), inter.pos));
+    let shortest = intersections.iter().min_by_key(|inter| (inter.combined_steps(), inter.pos));
+    if let Some(inter) = closest {
+        let label = format!("closest ({},{}) distance {}", inter.pos.x, inter.pos.y, inter.distance());
+        marker(&mut svg, inter.pos, stroke * 4.0, CLOSEST_COLOR, Option::from(label), size / 50.0);
+    }
+    if let Some(inter) = shortest {
+        let label = format!("fewest steps ({},{}) {} steps", inter.pos.x, inter.pos.y, inter.combined_steps());
+        marker(&mut svg, inter.pos, stroke * 4.0, SHORTEST_COLOR, Option::from(label), size / 50.0);
+    }
+
+    writeln!(svg, "</svg>").unwrap();
+    return svg;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{find_intersections, read_puzzle_input, trace_cables};
+
+    #[test]
+    fn example_svg() {
+        let wires = trace_cables(&read_puzzle_input("R8,U5,L5,D3\nU7,R6,D4,L4").unwrap());
+        let svg = wires_svg(&wires, &find_intersections(&wires));
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-0.5 -7.5 9.0 8.0\">"));
+        assert!(svg.contains("<polyline points=\"0,0 8,0 8,-5 3,-5 3,-2\""));
+        assert!(svg.contains("<polyline points=\"0,0 0,-7 6,-7 6,-3 2,-3\""));
+        assert!(svg.contains(">closest (3,-3) distance 6</text>"));
+        assert!(svg.contains(">fewest steps (6,-5) 30 steps</text>"));
+        assert_eq!(svg.matches("<circle").count(), 2 + 3);
+        assert_eq!(svg.matches("stroke-width=\"0.2\"").count(), 2);
+        assert!(svg.contains("<circle cx=\"3\" cy=\"-3\" r=\"0.4\" fill=\"black\"/>"));
+        assert!(!svg.contains("\"0.0\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::{BTreeMap, HashMap};
+use crate::grid::{Direction, Point};
+use super::{cross_lines, Line2D};
+
+/**
+ * Segment of a wire, index is the position of the segment in the wire
+ */
+#[derive(Eq, PartialEq, Clone, Debug, Copy)]
+pub struct WireLine {
+    pub wire: usize,
+    pub index: usize,
+    pub line: Line2D,
+}
+
+/**
+ * Run where two segments of different wires share points, from and to are the same point
+ * for perpendicular crossings and the ends of the shared part for collinear segments
+ */
+#[derive(Eq, PartialEq, Clone, Debug, Copy)]
+pub struct Contact {
+    pub from: Point<i32>,
+    pub to: Point<i32>,
+    pub a: WireLine,
+    pub b: WireLine,
+}
+
+impl Line2D {
+    /**
+     * Smallest and largest coordinate covered along the direction, the end point belongs to the next segment
+     */
+    fn span(self: &Self) -> (i32, i32) {
+        let last = self.dir.move_point(self.start, self.len - 1);
+        let (a, b) = if self.dir.is_horizontal() { (self.start.x, last.x) } else { (self.start.y, last.y) };
+        return (i32::min(a, b), i32::max(a, b));
+    }
+
+    /**
+     * The coordinate that stays the same along the segment
+     */
+    fn level(self: &Self) -> i32 {
+        return if self.dir.is_horizontal() { self.start.y } else { self.start.x };
+    }
+
+    fn point_at(self: &Self, pos: i32) -> Point<i32> {
+        return if self.dir.is_horizontal() { Point::from(pos, self.start.y) } else { Point::from(self.start.x, pos) };
+    }
+}
+
+fn flatten(wires: &Vec<Vec<Line2D>>) -> Vec<WireLine> {
+    let mut lines = Vec::new();
+    for (wire, cable) in wires.iter().enumerate() {
+        for (index, line) in cable.iter().enumerate() {
+            if line.len > 0 {
+                lines.push(WireLine {
+                    wire: wire,
+                    index: index,
+                    line: *line
+                });
+            }
+        }
+    }
+    return lines;
+}
+
+/**
+ * Sweep from left to right, horizontal segments are active while the sweep is over them
+ * and every vertical segment looks up the active ones in its y range
+ */
+fn perpendicular_contacts(lines: &Vec<WireLine>, contacts: &mut Vec<Contact>) {
+    const INSERT: u8 = 0;
+    const QUERY: u8 = 1;
+    const REMOVE: u8 = 2;
+
+    let mut events: Vec<(i32, u8, usize)> = Vec::new();
+    for (id, wl) in lines.iter().enumerate() {
+        let (min, max) = wl.line.span();
+        if wl.line.dir.is_horizontal() {
+            events.push((min, INSERT, id));
+            events.push((max, REMOVE, id));
+        }
+        else {
+            events.push((wl.line.level(), QUERY, id));
+        }
+    }
+    events.sort();
+
+    let mut active: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+    for (_x, kind, id) in events {
+        let wl = &lines[id];
+        match kind {
+            INSERT => active.entry(wl.line.level()).or_insert(Vec::new()).push(id),
+            REMOVE => {
+                let ids = active.get_mut(&wl.line.level()).unwrap();
+                ids.retain(|other| *other != id);
+                if ids.is_empty() {
+                    active.remove(&wl.line.level());
+                }
+            },
+            _ => {
+                let (min, max) = wl.line.span();
+                for (_y, ids) in active.range(min..=max) {
+                    for other in ids {
+                        let hl = &lines[*other];
+                        if hl.wire == wl.wire {
+                            continue;
+                        }
+                        if let Some(pt) = cross_lines(hl.line, wl.line) {
+                            let (a, b) = if hl.wire < wl.wire { (*hl, *wl) } else { (*wl, *hl) };
+                            contacts.push(Contact { from: pt, to: pt, a: a, b: b });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Segments on the same row or column sorted by their start, a segment overlaps
+ * every earlier one that hasn't ended before it starts
+ */
+fn collinear_contacts(lines: &Vec<WireLine>, contacts: &mut Vec<Contact>) {
+    let mut groups: HashMap<(bool, i32), Vec<usize>> = HashMap::new();
+    for (id, wl) in lines.iter().enumerate() {
+        groups.entry((wl.line.dir.is_horizontal(), wl.line.level())).or_insert(Vec::new()).push(id);
+    }
+
+    for (_key, mut ids) in groups {
+        ids.sort_by_key(|id| lines[*id].line.span());
+        let mut active: Vec<usize> = Vec::new();
+        for id in ids {
+            let wl = &lines[id];
+            let (start, end) = wl.line.span();
+            active.retain(|other| lines[*other].line.span().1 >= start);
+            for other in &active {
+                let ol = &lines[*other];
+                if ol.wire == wl.wire {
+                    continue;
+                }
+                let to = i32::min(end, ol.line.span().1);
+                let (a, b) = if ol.wire < wl.wire { (*ol, *wl) } else { (*wl, *ol) };
+                contacts.push(Contact { from: wl.line.point_at(start), to: wl.line.point_at(to), a: a, b: b });
+            }
+            active.push(id);
+        }
+    }
+}
+
+/**
+ * xorshift so the benchmark and tests don't need a random crate
+ */
+struct Random(u64);
+
+impl Random {
+    fn next(self: &mut Self, max: i32) -> i32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return (self.0 % max as u64) as i32;
+    }
+}
+
+fn random_wire(random: &mut Random, segments: usize, max_len: i32) -> Vec<Line2D> {
+    let mut start = Point::from(0, 0);
+    let mut steps = 0;
+    let mut lines = Vec::new();
+    for _i in 0..segments {
+        let dir = Direction::ALL[random.next(4) as usize];
+        let len = random.next(max_len) + 1;
+        lines.push(Line2D { start: start, dir: dir, len: len, steps: steps });
+        start = dir.move_point(start, len);
+        steps += len;
+    }
+    return lines;
+}
+
+/**
+ * Wires walking in random directions from the origin, the same seed gives the same wires
+ */
+pub fn random_wires(seed: u64, wires: usize, segments: usize, max_len: i32) -> Vec<Vec<Line2D>> {
+    let mut random = Random(seed);
+    return (0..wires).map(|_wire| random_wire(&mut random, segments, max_len)).collect();
+}
+
+/**
+ * All places where segments of different wires share points, including collinear overlaps
+ */
+pub fn find_contacts(wires: &Vec<Vec<Line2D>>) -> Vec<Contact> {
+    let lines = flatten(wires);
+    let mut contacts = Vec::new();
+    perpendicular_contacts(&lines, &mut contacts);
+    collinear_contacts(&lines, &mut contacts);
+    return contacts;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Walk every wire point by point, slow but obviously correct
+     */
+    fn shared_points(wires: &Vec<Vec<Line2D>>) -> Vec<Point<i32>> {
+        let mut seen: HashMap<Point<i32>, Vec<usize>> = HashMap::new();
+        for (wire, lines) in wires.iter().enumerate() {
+            for line in lines {
+                for i in 0..line.len {
+                    let wires = seen.entry(line.dir.move_point(line.start, i)).or_insert(Vec::new());
+                    if !wires.contains(&wire) {
+                        wires.push(wire);
+                    }
+                }
+            }
+        }
+        let mut points: Vec<Point<i32>> = seen.into_iter().filter(|(_pt, wires)| wires.len() > 1).map(|(pt, _wires)| pt).collect();
+        points.sort();
+        return points;
+    }
+
+    fn contact_points(contacts: &Vec<Contact>) -> Vec<Point<i32>> {
+        let mut points = Vec::new();
+        for contact in contacts {
+            for x in i32::min(contact.from.x, contact.to.x)..=i32::max(contact.from.x, contact.to.x) {
+                for y in i32::min(contact.from.y, contact.to.y)..=i32::max(contact.from.y, contact.to.y) {
+                    points.push(Point::from(x, y));
+                }
+            }
+        }
+        points.sort();
+        points.dedup();
+        return points;
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        let a = Line2D { start: Point::from(0, 0), dir: Direction::RIGHT, len: 8, steps: 0 };
+        let b = Line2D { start: Point::from(2, 0), dir: Direction::LEFT, len: 5, steps: 0 };
+        let contacts = find_contacts(&vec![vec![a], vec![b]]);
+        assert_eq!(contacts.len(), 1);
+        assert_eq!((contacts[0].from, contacts[0].to), (Point::from(0, 0), Point::from(2, 0)));
+    }
+
+    #[test]
+    fn matches_point_walk() {
+        for round in 0..20 {
+            let wires = random_wires(0x2545F4914F6CDD1D + round, 3, 60, 12);
+            assert_eq!(contact_points(&find_contacts(&wires)), shared_points(&wires));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+mod rules;
+
+use std::error::Error;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+use self::rules::Rule;
+
+#[derive(Default)]
+pub struct Puzzle {
+    lower: u64,
+    upper: u64
+}
+
+const PART1_POLICY: [Rule; 2] = [Rule::NonDecreasing, Rule::Pair];
+const PART2_POLICY: [Rule; 2] = [Rule::NonDecreasing, Rule::ExactPair];
+
+fn solve_puzzle(lower: u64, upper: u64) -> (u64, u64){
+    return (rules::count_matching(&PART1_POLICY, lower, upper), rules::count_matching(&PART2_POLICY, lower, upper));
+}
+
+fn parse_range(text: &str) -> Result<(u64, u64), String> {
+    let parts: Vec<&str> = text.trim().split("-").collect();
+    let bound = |part: &str| part.trim().parse::<u64>().map_err(|_e| format!("Invalid number \"{}\" in range \"{}\"", part.trim(), text.trim()));
+    if parts.len() != 2 {
+        return Err(format!("Expected a range like 123456-654321 but got \"{}\"", text.trim()));
+    }
+    return Ok((bound(parts[0])?, bound(parts[1])?));
+}
+
+/**
+ * Arguments of a tool command: an optional range (the puzzle input by default), a number to check,
+ * --rules <policy>, --limit <n> and --sample <n>
+ */
+struct ToolArgs {
+    range: Option<(u64, u64)>,
+    number: Option<u64>,
+    policy: Option<Vec<Rule>>,
+    limit: u64,
+    sample: Option<u64>
+}
+
+const DEFAULT_LIMIT: u64 = 100;
+
+fn parse_tool_args(args: &[String]) -> Result<ToolArgs, String> {
+    let mut tool_args = ToolArgs {
+        range: Option::None,
+        number: Option::None,
+        policy: Option::None,
+        limit: DEFAULT_LIMIT,
+        sample: Option::None
+    };
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        let mut value = |name: &str| {
+            i += 1;
+            return args.get(i).ok_or(format!("{} expects a value", name));
+        };
+        let count = |name: &str, text: &String| text.parse::<u64>().map_err(|_e| format!("{} expects a number but got \"{}\"", name, text));
+        match arg {
+            "--rules" => tool_args.policy = Option::from(rules::parse_policy(value("--rules")?)?),
+            "--limit" => tool_args.limit = count("--limit", value("--limit")?)?,
+            "--sample" => tool_args.sample = Option::from(count("--sample", value("--sample")?)?),
+            arg if arg.starts_with("--") => return Err(format!("Unknown option \"{}\"", arg)),
+            arg if arg.contains("-") => tool_args.range = Option::from(parse_range(arg)?),
+            arg => tool_args.number = Option::from(arg.trim().parse::<u64>().map_err(|_e| format!("Invalid number \"{}\"", arg))?)
+        }
+        i += 1;
+    }
+    return Ok(tool_args);
+}
+
+/**
+ * The custom policy or the policies of both parts
+ */
+fn tool_policies(policy: Option<Vec<Rule>>) -> Vec<(String, Vec<Rule>)> {
+    return match policy {
+        Some(policy) => vec![(String::from("custom"), policy)],
+        None => vec![(String::from("part 1"), PART1_POLICY.to_vec()), (String::from("part 2"), PART2_POLICY.to_vec())]
+    };
+}
+
+/**
+ * Positions of count matches spread evenly from the first to the last one
+ */
+fn sample_positions(count: u64, size: u64) -> Vec<u64> {
+    if size >= count {
+        return (0..count).collect();
+    }
+    if size == 1 {
+        return vec![0];
+    }
+    return (0..size).map(|i| (i as u128 * (count - 1) as u128 / (size - 1) as u128) as u64).collect();
+}
+
+fn check_tool(args: &ToolArgs) -> Result<String, String> {
+    let number = args.number.ok_or(String::from("check expects a number"))?;
+    let mut output = String::new();
+    for (name, policy) in tool_policies(args.policy.clone()) {
+        let result = match rules::check(&policy, number) {
+            Ok(()) => String::from("valid"),
+            Err(reason) => format!("invalid, {}", reason)
+        };
+        output += &format!("{} ({}): {} is {}\n", name, rules::format_policy(&policy), number, result);
+    }
+    return Ok(output);
+}
+
+fn list_tool(args: &ToolArgs, lower: u64, upper: u64) -> Result<String, String> {
+    let mut output = String::new();
+    for (name, policy) in tool_policies(args.policy.clone()) {
+        let count = rules::count_matching(&policy, lower, upper);
+        let positions = match args.sample {
+            Some(size) => sample_positions(count, size),
+            None => (0..u64::min(count, args.limit)).collect()
+        };
+        output += &format!("{} {}-{} ({}): {} matching\n", name, lower, upper, rules::format_policy(&policy), count);
+        for number in rules::select_matching(&policy, lower, upper, &positions)? {
+            output += &format!("{}\n", number);
+        }
+        if args.sample.is_none() && count > args.limit {
+            output += &format!("... {} more, use --limit or --sample\n", count - args.limit);
+        }
+    }
+    return Ok(output);
+}
+
+const TOOL_USAGE: &str = "Usage: aoc tool 4 count [<lower-upper>] [--rules <rule,...>]
+       aoc tool 4 check <number> [--rules <rule,...>]
+       aoc tool 4 list [<lower-upper>] [--rules <rule,...>] [--limit <n>] [--sample <n>]
+Rules: non-decreasing, pair, exact-pair, count(<digit>)<bounds>, length<bounds>
+Bounds: =n, >=n, <=n or =min..max
+";
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Secure Container";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_04/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let (lower, upper) = parse_range(input.text()?)?;
+        self.lower = lower;
+        self.upper = upper;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return Answer::from(solve_puzzle(self.lower, self.upper).0);
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return Answer::from(solve_puzzle(self.lower, self.upper).1);
+    }
+
+    fn tool(self: &mut Self, args: &[String], input: &dyn Fn() -> Result<Input, String>) -> Option<Result<String, String>> {
+        let command = args.first().map(|arg| arg.as_str()).unwrap_or("");
+        if !["count", "check", "list"].contains(&command) {
+            return Option::from(Err(String::from(TOOL_USAGE)));
+        }
+
+        return Option::from(parse_tool_args(&args[1..]).and_then(|args| {
+            if command == "check" {
+                return check_tool(&args);
+            }
+            let (lower, upper) = match args.range {
+                Some(range) => range,
+                None => {
+                    self.load_input(input)?;
+                    (self.lower, self.upper)
+                }
+            };
+            if command == "list" {
+                return list_tool(&args, lower, upper);
+            }
+            return Ok(tool_policies(args.policy).iter().map(|(name, policy)| {
+                format!("{} {}-{} ({}): {}\n", name, lower, upper, rules::format_policy(policy), rules::count_matching(policy, lower, upper))
+            }).collect());
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(rules::check(&PART1_POLICY, 111111), Ok(()));
+        assert!(rules::check(&PART1_POLICY, 223450).is_err());
+        assert!(rules::check(&PART1_POLICY, 123789).is_err());
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(rules::check(&PART2_POLICY, 112233), Ok(()));
+        assert!(rules::check(&PART2_POLICY, 123444).is_err());
+        assert_eq!(rules::check(&PART2_POLICY, 111122), Ok(()));
+    }
+
+    #[test]
+    fn solve_small_range() {
+        // 111111 to 111119 and 111122 never decrease, only 111122 has an exact pair
+        assert_eq!(solve_puzzle(111111, 111122), (10, 1));
+    }
+
+    fn tool_output(args: &[&str]) -> Result<String, String> {
+        let mut puzzle = Puzzle::default();
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        return puzzle.tool(&args, &|| Ok(Input { name: String::from("test"), data: b"100-300\n".to_vec() })).unwrap();
+    }
+
+    #[test]
+    fn check_tool_explains() {
+        assert_eq!(tool_output(&["check", "123444"]).unwrap(),
+            "part 1 (non-decreasing,pair): 123444 is valid\n\
+             part 2 (non-decreasing,exact-pair): 123444 is invalid, exact-pair: no group of exactly two, only larger groups 444\n");
+        assert_eq!(tool_output(&["check", "1231", "--rules", "non-decreasing"]).unwrap(),
+            "custom (non-decreasing): 1231 is invalid, non-decreasing: digit decreases at position 4 (1 after 3)\n");
+        assert!(tool_output(&["check"]).is_err());
+    }
+
+    #[test]
+    fn list_tool_limits_and_samples() {
+        assert_eq!(tool_output(&["list", "--rules", "non-decreasing,pair", "--limit", "3"]).unwrap(),
+            "custom 100-300 (non-decreasing,pair): 32 matching\n111\n112\n113\n... 29 more, use --limit or --sample\n");
+        assert_eq!(tool_output(&["list", "1-99", "--rules", "pair", "--sample", "3"]).unwrap(),
+            "custom 1-99 (pair): 9 matching\n11\n55\n99\n");
+        assert_eq!(sample_positions(10, 4), vec![0, 3, 6, 9]);
+        assert_eq!(sample_positions(2, 4), vec![0, 1]);
+        assert!(tool_output(&["list", "--limit", "x"]).is_err());
+    }
+
+    #[test]
+    fn tool_reads_input_on_demand() {
+        let mut puzzle = Puzzle::default();
+        let missing = || Err(String::from("Error while reading puzzle input! missing.txt: not found"));
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+        assert_eq!(puzzle.tool(&args(&["count", "111111-111122"]), &missing).unwrap(),
+            Ok(String::from("part 1 111111-111122 (non-decreasing,pair): 10\npart 2 111111-111122 (non-decreasing,exact-pair): 1\n")));
+        assert_eq!(puzzle.tool(&args(&["count"]), &missing).unwrap(),
+            Err(String::from("Error while reading puzzle input! missing.txt: not found")));
+        assert_eq!(tool_output(&["count", "--rule", "pair"]), Err(String::from("Unknown option \"--rule\"")));
+    }
+}
@@ -0,0 +1,442 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/**
+ * Single password rule, a policy is a list of rules that all have to match
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    // Going from left to right the digits never decrease
+    NonDecreasing,
+    // Two adjacent digits are the same
+    Pair,
+    // Two adjacent digits are the same and not part of a larger group
+    ExactPair,
+    // The digit appears between min and max times
+    DigitCount { digit: u8, min: u8, max: u8 },
+    // The number has between min and max digits
+    Length { min: u8, max: u8 },
+}
+
+/**
+ * Rules look at the digits one by one, every rule keeps a small state so numbers
+ * with the same state can be counted together
+ */
+type State = u32;
+
+const NO_DIGIT: u32 = 10;
+
+impl Rule {
+    fn start(self: &Self) -> State {
+        return match self {
+            Rule::NonDecreasing => NO_DIGIT,
+            Rule::Pair => NO_DIGIT * 2,
+            Rule::ExactPair => NO_DIGIT * 8,
+            Rule::DigitCount { .. } | Rule::Length { .. } => 0
+        };
+    }
+
+    /**
+     * State after the next digit, None if no number continuing like this can match anymore
+     */
+    fn next(self: &Self, state: State, digit: u8) -> Option<State> {
+        let digit = digit as u32;
+        return match self {
+            Rule::NonDecreasing => if state != NO_DIGIT && digit < state { Option::None } else { Option::from(digit) },
+            Rule::Pair => {
+                let found = state % 2 == 1 || state / 2 == digit;
+                Option::from(digit * 2 + found as u32)
+            },
+            Rule::ExactPair => {
+                let (last, run, found) = (state / 8, (state / 2) % 4, state % 2 == 1);
+                if last == digit {
+                    Option::from(digit * 8 + u32::min(run + 1, 3) * 2 + found as u32)
+                }
+                else {
+                    Option::from(digit * 8 + 2 + (found || run == 2) as u32)
+                }
+            },
+            Rule::DigitCount { digit: counted, max, .. } => {
+                let count = state + (digit == *counted as u32) as u32;
+                if count > *max as u32 { Option::None } else { Option::from(count) }
+            },
+            Rule::Length { max, .. } => if state + 1 > *max as u32 { Option::None } else { Option::from(state + 1) }
+        };
+    }
+
+    fn accepts(self: &Self, state: State) -> bool {
+        return match self {
+            Rule::NonDecreasing => true,
+            Rule::Pair => state % 2 == 1,
+            Rule::ExactPair => state % 2 == 1 || (state / 2) % 4 == 2,
+            Rule::DigitCount { min, .. } => state >= *min as u32,
+            Rule::Length { min, .. } => state >= *min as u32
+        };
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Rule::NonDecreasing => write!(f, "non-decreasing"),
+            Rule::Pair => write!(f, "pair"),
+            Rule::ExactPair => write!(f, "exact-pair"),
+            Rule::DigitCount { digit, min, max } => write!(f, "count({}){}", digit, format_bounds(*min, *max)),
+            Rule::Length { min, max } => write!(f, "length{}", format_bounds(*min, *max))
+        };
+    }
+}
+
+fn format_bounds(min: u8, max: u8) -> String {
+    return match (min, max) {
+        (min, max) if min == max => format!("={}", min),
+        (min, u8::MAX) => format!(">={}", min),
+        (0, max) => format!("<={}", max),
+        (min, max) => format!("={}..{}", min, max)
+    };
+}
+
+fn parse_bounds(text: &str) -> Result<(u8, u8), String> {
+    let error = || format!("Invalid bounds \"{}\", expected =n, =min..max, >=n or <=n", text);
+    let number = |part: &str| part.trim().parse::<u8>().map_err(|_e| error());
+    if let Some(min) = text.strip_prefix(">=") {
+        return Ok((number(min)?, u8::MAX));
+    }
+    if let Some(max) = text.strip_prefix("<=") {
+        return Ok((0, number(max)?));
+    }
+    let text = text.strip_prefix("=").ok_or(error())?;
+    if let Some((min, max)) = text.split_once("..") {
+        return Ok((number(min)?, number(max)?));
+    }
+    let exact = number(text)?;
+    return Ok((exact, exact));
+}
+
+/**
+ * Parse a comma separated policy like "non-decreasing,exact-pair,count(7)>=2,length=6"
+ */
+pub fn parse_policy(text: &str) -> Result<Vec<Rule>, String> {
+    let mut policy = Vec::new();
+    for part in text.split(",").map(|part| part.trim()).filter(|part| !part.is_empty()) {
+        let rule = match part {
+            "non-decreasing" => Rule::NonDecreasing,
+            "pair" => Rule::Pair,
+            "exact-pair" => Rule::ExactPair,
+            _ if part.starts_with("length") => {
+                let (min, max) = parse_bounds(&part["length".len()..])?;
+                Rule::Length { min: min, max: max }
+            },
+            _ if part.starts_with("count(") && part[6..].contains(')') => {
+                let close = part.find(')').unwrap();
+                let digit = part[6..close].trim().parse::<u8>().ok().filter(|digit| *digit < 10)
+                    .ok_or(format!("Invalid digit in \"{}\"", part))?;
+                let (min, max) = parse_bounds(&part[close + 1..])?;
+                Rule::DigitCount { digit: digit, min: min, max: max }
+            },
+            _ => return Err(format!("Unknown rule \"{}\"", part))
+        };
+        policy.push(rule);
+    }
+    return Ok(policy);
+}
+
+pub fn format_policy(policy: &[Rule]) -> String {
+    return policy.iter().map(|rule| rule.to_string()).collect::<Vec<String>>().join(",");
+}
+
+/**
+ * Digits of the number, most significant first
+ */
+fn digits(number: u64) -> Vec<u8> {
+    return number.to_string().bytes().map(|byte| byte - b'0').collect();
+}
+
+struct Counter<'a> {
+    policy: &'a [Rule],
+    limit: Vec<u8>,
+    memo: HashMap<(usize, Vec<State>), u64>,
+}
+
+impl<'a> Counter<'a> {
+    /**
+     * Numbers from 1 up to the limit that continue the digits before pos with the given states.
+     * Once a number is below the limit (not tight) the rest only depends on pos and the states.
+     */
+    fn count(self: &mut Self, pos: usize, tight: bool, started: bool, states: &Vec<State>) -> u64 {
+        if pos == self.limit.len() {
+            let accepted = started && self.policy.iter().zip(states.iter()).all(|(rule, state)| rule.accepts(*state));
+            return accepted as u64;
+        }
+
+        let key = (pos, states.clone());
+        if !tight && started {
+            if let Some(count) = self.memo.get(&key) {
+                return *count;
+            }
+        }
+
+        let max = if tight { self.limit[pos] } else { 9 };
+        let mut total = 0;
+        for digit in 0..=max {
+            let next_tight = tight && digit == max;
+            if !started && digit == 0 {
+                // Leading zeros are not part of the number
+                total += self.count(pos + 1, next_tight, false, states);
+                continue;
+            }
+
+            let next: Option<Vec<State>> = self.policy.iter().zip(states.iter()).map(|(rule, state)| rule.next(*state, digit)).collect();
+            if let Some(next) = next {
+                total += self.count(pos + 1, next_tight, true, &next);
+            }
+        }
+
+        if !tight && started {
+            self.memo.insert(key, total);
+        }
+        return total;
+    }
+
+    /**
+     * The k-th matching number up to the limit counting from 1, walks down the digits and skips
+     * every digit whose numbers are all before k. Counts are memoized so asking for many k is cheap.
+     */
+    fn nth(self: &mut Self, mut k: u64) -> Option<u64> {
+        let (mut tight, mut started) = (true, false);
+        let mut states: Vec<State> = self.policy.iter().map(|rule| rule.start()).collect();
+        let mut number = 0;
+        for pos in 0..self.limit.len() {
+            let max = if tight { self.limit[pos] } else { 9 };
+            let mut chosen = Option::None;
+            for digit in 0..=max {
+                let next_tight = tight && digit == max;
+                let next_started = started || digit != 0;
+                let next: Option<Vec<State>> = if next_started {
+                    self.policy.iter().zip(states.iter()).map(|(rule, state)| rule.next(*state, digit)).collect()
+                }
+                else {
+                    Option::from(states.clone())
+                };
+                if let Some(next) = next {
+                    let count = self.count(pos + 1, next_tight, next_started, &next);
+                    if k <= count {
+                        chosen = Option::from((digit, next_tight, next_started, next));
+                        break;
+                    }
+                    k -= count;
+                }
+            }
+
+            let (digit, next_tight, next_started, next) = chosen?;
+            number = number * 10 + digit as u64;
+            tight = next_tight;
+            started = next_started;
+            states = next;
+        }
+        return if started { Option::from(number) } else { Option::None };
+    }
+}
+
+fn counter(policy: &[Rule], limit: u64) -> Counter<'_> {
+    return Counter {
+        policy: policy,
+        limit: digits(limit),
+        memo: HashMap::new()
+    };
+}
+
+fn count_up_to(policy: &[Rule], limit: u64) -> u64 {
+    let start: Vec<State> = policy.iter().map(|rule| rule.start()).collect();
+    return counter(policy, limit).count(0, true, false, &start);
+}
+
+/**
+ * Count the positive numbers in the inclusive range that match every rule of the policy
+ */
+pub fn count_matching(policy: &[Rule], lower: u64, upper: u64) -> u64 {
+    if lower > upper {
+        return 0;
+    }
+    let below = if lower <= 1 { 0 } else { count_up_to(policy, lower - 1) };
+    return count_up_to(policy, upper) - below;
+}
+
+/**
+ * Matching numbers of the inclusive range at the given positions, counted from 0 in ascending order.
+ * Positions past the last match are left out, positions that don't fit into a u64 are an error.
+ */
+pub fn select_matching(policy: &[Rule], lower: u64, upper: u64, positions: &[u64]) -> Result<Vec<u64>, String> {
+    if lower > upper {
+        return Ok(Vec::new());
+    }
+    let below = if lower <= 1 { 0 } else { count_up_to(policy, lower - 1) };
+    let mut counter = counter(policy, upper);
+    let mut numbers = Vec::new();
+    for pos in positions {
+        let k = pos.checked_add(1).and_then(|k| k.checked_add(below))
+            .ok_or(format!("Position {} is too large for the range {}-{}", pos, lower, upper))?;
+        numbers.extend(counter.nth(k));
+    }
+    return Ok(numbers);
+}
+
+/**
+ * Check a single number against the policy, returns the first rule that doesn't match
+ */
+pub fn first_failed_rule(policy: &[Rule], number: u64) -> Option<Rule> {
+    for rule in policy {
+        let mut state = Option::from(rule.start());
+        for digit in digits(number) {
+            state = state.and_then(|state| rule.next(state, digit));
+        }
+        if !state.map_or(false, |state| rule.accepts(state)) {
+            return Option::from(*rule);
+        }
+    }
+    return Option::None;
+}
+
+/**
+ * Runs of the same digit from left to right as (digit, length)
+ */
+fn digit_groups(digits: &[u8]) -> Vec<(u8, usize)> {
+    let mut groups: Vec<(u8, usize)> = Vec::new();
+    for digit in digits {
+        match groups.last_mut() {
+            Some((last, streak)) if last == digit => *streak += 1,
+            _ => groups.push((*digit, 1))
+        }
+    }
+    return groups;
+}
+
+/**
+ * Why the number doesn't match the rule, positions are counted from 1 starting with the leftmost digit
+ */
+pub fn explain_failure(rule: &Rule, number: u64) -> String {
+    let digits = digits(number);
+    let groups = digit_groups(&digits);
+    return match rule {
+        Rule::NonDecreasing => match digits.windows(2).position(|pair| pair[1] < pair[0]) {
+            Some(pos) => format!("digit decreases at position {} ({} after {})", pos + 2, digits[pos + 1], digits[pos]),
+            None => String::from("digits never decrease")
+        },
+        Rule::Pair => String::from("no two adjacent digits are the same"),
+        Rule::ExactPair => {
+            let larger: Vec<String> = groups.iter().filter(|(_digit, len)| *len > 2)
+                .map(|(digit, len)| digit.to_string().repeat(*len)).collect();
+            if larger.is_empty() {
+                String::from("no group of exactly two")
+            }
+            else {
+                format!("no group of exactly two, only larger groups {}", larger.join(", "))
+            }
+        },
+        Rule::DigitCount { digit, min, max } => {
+            let count = digits.iter().filter(|other| *other == digit).count();
+            format!("digit {} appears {} times, expected {}", digit, count, format_bounds(*min, *max))
+        },
+        Rule::Length { min, max } => format!("{} digits, expected {}", digits.len(), format_bounds(*min, *max))
+    };
+}
+
+/**
+ * Check a number against the policy, explains the first rule that doesn't match
+ */
+pub fn check(policy: &[Rule], number: u64) -> Result<(), String> {
+    return match first_failed_rule(policy, number) {
+        Some(rule) => Err(format!("{}: {}", rule, explain_failure(&rule, number))),
+        None => Ok(())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_policies() {
+        let policy = parse_policy("non-decreasing, exact-pair,count(7)>=2,length=6,count(0)<=1").unwrap();
+        assert_eq!(policy, vec![
+            Rule::NonDecreasing,
+            Rule::ExactPair,
+            Rule::DigitCount { digit: 7, min: 2, max: 255 },
+            Rule::Length { min: 6, max: 6 },
+            Rule::DigitCount { digit: 0, min: 0, max: 1 }
+        ]);
+        assert_eq!(parse_policy(&format_policy(&policy)).unwrap(), policy);
+        assert!(parse_policy("sorted").is_err());
+        assert!(parse_policy("count(12)=1").is_err());
+        assert!(parse_policy("length>=x").is_err());
+    }
+
+    #[test]
+    fn single_numbers() {
+        let policy = parse_policy("non-decreasing,exact-pair").unwrap();
+        assert_eq!(first_failed_rule(&policy, 112233), Option::None);
+        assert_eq!(first_failed_rule(&policy, 123444), Option::from(Rule::ExactPair));
+        assert_eq!(first_failed_rule(&policy, 111122), Option::None);
+        assert_eq!(first_failed_rule(&policy, 223450), Option::from(Rule::NonDecreasing));
+    }
+
+    #[test]
+    fn matches_enumeration() {
+        let policies = ["non-decreasing,pair", "non-decreasing,exact-pair", "exact-pair,count(3)>=1", "length=2..3,pair", ""];
+        for text in policies.iter() {
+            let policy = parse_policy(text).unwrap();
+            let expected = (1..=25000).filter(|number| first_failed_rule(&policy, *number).is_none()).count() as u64;
+            assert_eq!(count_matching(&policy, 1, 25000), expected, "{}", text);
+            let expected = (987..=4321).filter(|number| first_failed_rule(&policy, *number).is_none()).count() as u64;
+            assert_eq!(count_matching(&policy, 987, 4321), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn wide_ranges() {
+        // Non-decreasing numbers with up to 18 digits are multisets of 1-9, C(27, 9) - 1
+        assert_eq!(count_matching(&parse_policy("non-decreasing").unwrap(), 1, 1_000_000_000_000_000_000), 4686824);
+        assert_eq!(count_matching(&[], 1, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn explain_failures() {
+        let policy = parse_policy("non-decreasing,exact-pair,count(9)>=1,length=6").unwrap();
+        assert_eq!(check(&policy, 112299), Ok(()));
+        assert_eq!(check(&policy, 223450), Err(String::from("non-decreasing: digit decreases at position 6 (0 after 5)")));
+        assert_eq!(check(&policy, 124444), Err(String::from("exact-pair: no group of exactly two, only larger groups 4444")));
+        assert_eq!(check(&policy, 123456), Err(String::from("exact-pair: no group of exactly two")));
+        assert_eq!(check(&policy, 112233), Err(String::from("count(9)>=1: digit 9 appears 0 times, expected >=1")));
+        assert_eq!(check(&policy, 1122339), Err(String::from("length=6: 7 digits, expected =6")));
+        assert_eq!(digit_groups(&[1, 1, 2, 3, 3, 3]), vec![(1, 2), (2, 1), (3, 3)]);
+    }
+
+    #[test]
+    fn puzzle_policies_match_digit_groups() {
+        let (mut pair, mut exact_pair) = (0, 0);
+        for number in 100000..=200000 {
+            let digits = digits(number);
+            if digits.windows(2).any(|two| two[1] < two[0]) {
+                continue;
+            }
+            let groups = digit_groups(&digits);
+            pair += groups.iter().any(|(_digit, len)| *len >= 2) as u64;
+            exact_pair += groups.iter().any(|(_digit, len)| *len == 2) as u64;
+        }
+        assert_eq!(count_matching(&[Rule::NonDecreasing, Rule::Pair], 100000, 200000), pair);
+        assert_eq!(count_matching(&[Rule::NonDecreasing, Rule::ExactPair], 100000, 200000), exact_pair);
+    }
+
+    #[test]
+    fn select_positions() {
+        let policy = parse_policy("non-decreasing,pair").unwrap();
+        let all: Vec<u64> = (90..=400).filter(|number| first_failed_rule(&policy, *number).is_none()).collect();
+        let positions: Vec<u64> = (0..all.len() as u64 + 3).collect();
+        assert_eq!(select_matching(&policy, 90, 400, &positions), Ok(all));
+        assert_eq!(select_matching(&policy, 90, 400, &[0, 2]), Ok(vec![99, 112]));
+        assert_eq!(select_matching(&[], 1, u64::MAX, &[u64::MAX - 1]), Ok(vec![u64::MAX]));
+        assert_eq!(select_matching(&policy, 400, 90, &[0]), Ok(vec![]));
+        assert!(select_matching(&[], 1, u64::MAX, &[u64::MAX]).is_err());
+        assert!(select_matching(&policy, 1_000_000_000, 1_000_000_000_000_000_000, &[u64::MAX - 5]).is_err());
+    }
+}
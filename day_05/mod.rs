@@ -0,0 +1,275 @@
+use std::vec::Vec;
+use std::collections::VecDeque;
+use std::error::Error;
+use crate::loader;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Debug)]
+enum EndReason {
+    EndCode,
+    CodeUnknown,
+    EndProgram,
+    AddressInvalid,
+    InputMissing
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Addressing {
+    Position,
+    Immediate
+}
+
+#[derive(Debug)]
+struct ProgramResult {
+    memory: Vec<i32>,
+    outputs: Vec<i32>,
+    code: EndReason,
+    counter: usize,
+}
+
+#[derive(Debug)]
+struct OpCode {
+    code: u8,
+    addr_mode: Vec<Addressing>,
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    mem: Vec<i32>
+}
+
+impl Addressing{
+    fn from(addrmode: u8) -> Self {
+        match addrmode {
+            0 => return Addressing::Position,
+            1 => return Addressing::Immediate,
+
+            _ => return Addressing::Position
+        }
+    }
+}
+
+impl OpCode{
+    fn from(code: i32) -> Self {
+        let opcode = (code % 100) as u8;
+        let mut modes = code / 100;
+        let mut mode_arr: Vec<Addressing> = Vec::new();
+        for _i in 0..get_opcode_params(opcode) {
+            mode_arr.push(Addressing::from((modes % 10) as u8));
+            modes = modes / 10;
+        }
+
+        return OpCode {
+            code: opcode,
+            addr_mode: mode_arr
+        }
+    }
+}
+
+fn get_opcode_params(opcode: u8) -> u8{
+    return match opcode {
+        1 | 2 | 7 | 8 => 3,
+        3 | 4 => 1,
+        5 | 6  => 2,
+        _ => 0
+    }
+}
+
+/**
+ * Address of a cell inside the program
+ */
+fn get_address(mem: &Vec<i32>, val: i32) -> Result<usize, EndReason> {
+    if val < 0 || val as usize >= mem.len() {
+        return Err(EndReason::AddressInvalid);
+    }
+    return Ok(val as usize);
+}
+
+/**
+ * Value of the i-th parameter of the instruction at the counter
+ */
+fn get_value(mem: &Vec<i32>, counter: usize, code: &OpCode, i: usize) -> Result<i32, EndReason> {
+    let val = *mem.get(counter + 1 + i).ok_or(EndReason::AddressInvalid)?;
+    return match code.addr_mode[i] {
+        Addressing::Immediate => Ok(val),
+        Addressing::Position => Ok(mem[get_address(mem, val)?])
+    }
+}
+
+/**
+ * Address the i-th parameter of the instruction at the counter writes to
+ */
+fn get_target(mem: &Vec<i32>, counter: usize, i: usize) -> Result<usize, EndReason> {
+    let val = *mem.get(counter + 1 + i).ok_or(EndReason::AddressInvalid)?;
+    return get_address(mem, val);
+}
+
+/**
+ * Run the instruction at the counter, returns the counter of the next one or the reason the program stopped
+ */
+fn execute(prog: &mut Vec<i32>, counter: usize, input: &mut VecDeque<i32>, outputs: &mut Vec<i32>) -> Result<usize, EndReason> {
+    let code = OpCode::from(prog[counter]);
+
+    match code.code {
+        1 | 2 | 7 | 8 => {
+            let a = get_value(prog, counter, &code, 0)?;
+            let b = get_value(prog, counter, &code, 1)?;
+            let c = get_target(prog, counter, 2)?;
+            prog[c] = match code.code {
+                1 => a + b,
+                2 => a * b,
+                7 => (a < b) as i32,
+                _ => (a == b) as i32
+            };
+            return Ok(counter + 4);
+        },
+        3 => {
+            let a = get_target(prog, counter, 0)?;
+            prog[a] = input.pop_front().ok_or(EndReason::InputMissing)?;
+            return Ok(counter + 2);
+        },
+        4 => {
+            outputs.push(get_value(prog, counter, &code, 0)?);
+            return Ok(counter + 2);
+        },
+        5 | 6 => {
+            let a = get_value(prog, counter, &code, 0)?;
+            if (a != 0) == (code.code == 5) {
+                let b = get_value(prog, counter, &code, 1)?;
+                return get_address(prog, b);
+            }
+            return Ok(counter + 3);
+        },
+        99 => return Err(EndReason::EndCode),
+        _ => return Err(EndReason::CodeUnknown)
+    }
+}
+
+fn run_program(prog_in: &Vec<i32>, input_in: &Vec<i32>) -> ProgramResult{
+    let mut prog = prog_in.clone();
+    let mut input: VecDeque<i32> = input_in.clone().into_iter().collect();
+    let mut outputs: Vec<i32> = Vec::new();
+    let mut counter = 0;
+    let mut end_reason = EndReason::EndProgram;
+
+    while counter < prog.len() {
+        match execute(&mut prog, counter, &mut input, &mut outputs) {
+            Ok(next) => counter = next,
+            Err(reason) => {
+                end_reason = reason;
+                break;
+            }
+        }
+    }
+
+    return ProgramResult {
+        memory: prog,
+        code: end_reason,
+        outputs: outputs,
+        counter: counter
+    }
+}
+
+/**
+ * The diagnostic code is the first output that isn't a passed test (0)
+ */
+fn diagnostic_code(result: &ProgramResult) -> Answer {
+    match result.code {
+        EndReason::CodeUnknown => return Answer::from(format!("Unknown opcode {} at counter {}", result.memory[result.counter], result.counter).as_str()),
+        EndReason::EndProgram => return Answer::from("The program ran past its end without halting"),
+        EndReason::AddressInvalid => return Answer::from(format!("The instruction at counter {} uses an address outside of the program", result.counter).as_str()),
+        EndReason::InputMissing => return Answer::from(format!("The program needs more input at counter {}", result.counter).as_str()),
+        EndReason::EndCode => {}
+    }
+    return match result.outputs.iter().find(|&&x| x != 0) {
+        Some(code) => Answer::from(*code),
+        None => Answer::from("The program printed no diagnostic code")
+    };
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Sunny with a Chance of Asteroids";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_05/program.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.mem = loader::load_program_i32(input)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        let result_1 = run_program(&self.mem, &vec![1]);
+        return diagnostic_code(&result_1);
+    }
+
+    fn part2(self: &Self) -> Answer {
+        let result_2 = run_program(&self.mem, &vec![5]);
+        return diagnostic_code(&result_2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_output(prog: &Vec<i32>, input: i32) -> i32 {
+        return run_program(prog, &vec![input]).outputs[0];
+    }
+
+    #[test]
+    fn comparison_examples() {
+        let equal_pos = vec![3,9,8,9,10,9,4,9,99,-1,8];
+        let less_pos = vec![3,9,7,9,10,9,4,9,99,-1,8];
+        let equal_imm = vec![3,3,1108,-1,8,3,4,3,99];
+        let less_imm = vec![3,3,1107,-1,8,3,4,3,99];
+        for (prog, input, expected) in [(&equal_pos, 8, 1), (&equal_pos, 7, 0), (&less_pos, 7, 1), (&less_pos, 8, 0),
+                                        (&equal_imm, 8, 1), (&equal_imm, 9, 0), (&less_imm, 5, 1), (&less_imm, 9, 0)] {
+            assert_eq!(first_output(prog, input), expected);
+        }
+    }
+
+    #[test]
+    fn jump_examples() {
+        let jump_pos = vec![3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9];
+        let jump_imm = vec![3,3,1105,-1,9,1101,0,0,12,4,12,99,1];
+        assert_eq!(first_output(&jump_pos, 0), 0);
+        assert_eq!(first_output(&jump_pos, 3), 1);
+        assert_eq!(first_output(&jump_imm, 0), 0);
+        assert_eq!(first_output(&jump_imm, 3), 1);
+    }
+
+    #[test]
+    fn compare_to_eight_example() {
+        let prog = vec![3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,
+            1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,
+            999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99];
+        assert_eq!(first_output(&prog, 7), 999);
+        assert_eq!(first_output(&prog, 8), 1000);
+        assert_eq!(first_output(&prog, 9), 1001);
+    }
+
+    #[test]
+    fn negative_parameters() {
+        assert_eq!(run_program(&vec![1101,100,-1,4,0], &vec![]).memory[4], 99);
+        assert_eq!(run_program(&vec![1002,4,3,4,33], &vec![]).memory[4], 99);
+    }
+
+    #[test]
+    fn diagnostic_codes() {
+        // Two passed tests and the diagnostic code
+        assert_eq!(diagnostic_code(&run_program(&vec![104,0,104,0,104,42,99], &vec![])), Answer::Number(42));
+        assert_eq!(diagnostic_code(&run_program(&vec![3,0,4,0,99], &vec![0])), Answer::from("The program printed no diagnostic code"));
+        assert_eq!(diagnostic_code(&run_program(&vec![104,0,42], &vec![])), Answer::from("Unknown opcode 42 at counter 2"));
+        assert_eq!(diagnostic_code(&run_program(&vec![104,0], &vec![])), Answer::from("The program ran past its end without halting"));
+        assert_eq!(diagnostic_code(&run_program(&vec![99], &vec![])), Answer::from("The program printed no diagnostic code"));
+        assert_eq!(diagnostic_code(&run_program(&vec![4,7,99], &vec![])), Answer::from("The instruction at counter 0 uses an address outside of the program"));
+        assert_eq!(diagnostic_code(&run_program(&vec![1,0,0], &vec![])), Answer::from("The instruction at counter 0 uses an address outside of the program"));
+        assert_eq!(diagnostic_code(&run_program(&vec![3,0,3,0,99], &vec![1])), Answer::from("The program needs more input at counter 2"));
+    }
+}
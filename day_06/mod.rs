@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::error::Error;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+use crate::report::Report;
+use crate::search;
+
+
+#[derive(Clone, Debug)]
+struct OrbitMap {
+    full_map: HashMap<String, Body>,
+    roots: Vec<String>
+}
+
+#[derive(Clone, Debug)]
+struct Body {
+    sub: Vec<String>,
+    parent: String
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    map: Option<OrbitMap>
+}
+
+
+/**
+ * One orbit like "COM)B" per line, blank lines are skipped. Every body orbits at most one other body.
+ */
+fn read_puzzle_input(input: &str) -> Result<HashMap<String, Body>, String> {
+    let mut bodies : HashMap<String, Body> = HashMap::new();
+    let mut parents: HashMap<String, String> = HashMap::new();
+
+    for (i, uline) in input.lines().enumerate() {
+        if uline.trim().is_empty() {
+            continue;
+        }
+        let parts: Vec<&str> = uline.trim().split(")").map(|part| part.trim()).collect();
+        if parts.len() != 2 || parts[0].is_empty() || parts[1].is_empty() {
+            return Err(format!("Invalid orbit \"{}\" on line {}, expected two bodies like COM)B", uline.trim(), i + 1));
+        }
+        if let Some(parent) = parents.insert(String::from(parts[1]), String::from(parts[0])) {
+            return Err(format!("{} orbits {} and {} on line {}", parts[1], parent, parts[0], i + 1));
+        }
+
+        let main_key = String::from(parts[0]);
+
+        let mut main_b = bodies.get_mut(&main_key);
+        if main_b.is_none() {
+            bodies.insert(main_key.clone(), Body {
+                sub: Vec::new(),
+                parent: String::from("")
+            });
+            main_b = bodies.get_mut(&main_key);
+        }
+
+        main_b.unwrap().sub.push(String::from(parts[1]));
+    }
+
+    return Ok(bodies);
+}
+
+
+fn map_depths(map: & HashMap<String, Body>) -> OrbitMap{
+    let mut dmap = map.clone();
+    let mut roots : Vec<String> = Vec::new();
+    for item in map.into_iter(){
+        let mut is_sub = false;
+        for item2 in map.into_iter(){
+            if (&item2.1.sub).into_iter().find(|&x| x == item.0).is_some() {
+                is_sub = true;
+                dmap.get_mut(item.0).unwrap().parent = item2.0.clone();
+                break;
+            }
+        }
+
+        if !is_sub {
+            roots.push(item.0.clone());
+        }
+    }
+
+    let mut omap = OrbitMap {
+        full_map: dmap,
+        roots: roots
+    };
+
+    for root in omap.roots.clone() {
+        put_ends_recursive(&mut omap, &root);
+    }
+
+    return omap;
+}
+
+fn put_ends_recursive(map:& mut OrbitMap, node_name: & String) {
+
+    let fmap = map.full_map.clone();
+    let node = fmap.get(node_name).unwrap();
+
+    for sub in &node.sub{
+        let sub_node = fmap.get(sub);
+        if sub_node.is_some() {
+            put_ends_recursive(map, &sub);
+        }
+        else {
+            map.full_map.insert(sub.clone(), Body {
+                sub: Vec::new(),
+                parent: node_name.clone()
+            });
+        }
+    }
+}
+
+fn count_orbits(map:& OrbitMap, root:& String, depth: u32) -> u32{
+    let mut orbits: u32 = depth;
+    let opt_body = map.full_map.get(root);
+
+    if opt_body.is_some() {
+        for sub in &opt_body.unwrap().sub{
+            orbits += count_orbits(map, sub, depth + 1);
+        }
+    }
+    
+    return orbits;
+}
+
+fn get_neighbors(map:& OrbitMap, node: &String) -> Vec<String> {
+    let body = match map.full_map.get(node) {
+        Some(body) => body,
+        None => return Vec::new()
+    };
+    let mut neighbors = body.sub.clone();
+    if body.parent.len() > 0 {
+        neighbors.push(body.parent.clone());
+    }
+    return neighbors;
+}
+
+/**
+ * Search from the body node_a is orbiting, returns the result and the body node_b is orbiting.
+ * None if one of them is missing or doesn't orbit anything.
+ */
+fn search_transfers(map:& OrbitMap, node_a: &String, node_b: &String) -> Option<(search::SearchResult<String>, String)>{
+    let from = &map.full_map.get(node_a)?.parent;
+    let to = &map.full_map.get(node_b)?.parent;
+    if from.is_empty() || to.is_empty() {
+        return Option::None;
+    }
+    return Option::from((search::bfs(from.clone(), |node| get_neighbors(map, node)), to.clone()));
+}
+
+/**
+ * Orbital transfers between the bodies node_a and node_b are orbiting, None if one of them
+ * is missing or doesn't orbit anything or the two aren't connected
+ */
+fn get_hops(map:& OrbitMap, node_a: &String, node_b: &String) -> Option<usize>{
+    let (result, to) = search_transfers(map, node_a, node_b)?;
+    return result.distance(&to).map(|distance| distance as usize);
+}
+
+/**
+ * Every body passed on the way from YOU to SAN, numbered by the transfers needed to reach it
+ */
+fn transfer_report(map:& OrbitMap) -> Report {
+    let mut report = Report::new(&["transfer", "body"]);
+    let path = search_transfers(map, &String::from("YOU"), &String::from("SAN")).and_then(|(result, to)| result.path(&to));
+    match path {
+        Some(path) => {
+            for (i, body) in path.iter().enumerate() {
+                report.add_row(vec![i.to_string(), body.clone()]);
+            }
+            report.add_note(format!("{} orbital transfers from YOU to SAN", path.len() - 1));
+        },
+        None => report.add_note(String::from("YOU and SAN are not both orbiting bodies of the same map"))
+    }
+    return report;
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Universal Orbit Map";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_06/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.map = Option::from(map_depths(&read_puzzle_input(input.text()?)?));
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        let map = self.map.as_ref().unwrap();
+        let mut orbits : u32 = 0;
+        for item in &map.roots{
+            orbits += count_orbits(&map, &item, 0);
+        }
+        return Answer::from(orbits);
+    }
+
+    fn part2(self: &Self) -> Answer {
+        let map = self.map.as_ref().unwrap();
+        return match get_hops(&map, &String::from("YOU"), &String::from("SAN")) {
+            Some(hops) => Answer::from(hops),
+            None => Answer::from("YOU and SAN are not both orbiting bodies of the same map")
+        };
+    }
+
+    fn report(self: &Self) -> Option<Report> {
+        return Option::from(transfer_report(self.map.as_ref().unwrap()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L";
+
+    #[test]
+    fn count_orbits_example() {
+        let map = map_depths(&read_puzzle_input(EXAMPLE).unwrap());
+        assert_eq!(map.roots, vec![String::from("COM")]);
+        assert_eq!(count_orbits(&map, &String::from("COM"), 0), 42);
+    }
+
+    #[test]
+    fn get_hops_example() {
+        let map = map_depths(&read_puzzle_input(&format!("{}\nK)YOU\nI)SAN", EXAMPLE)).unwrap());
+        assert_eq!(get_hops(&map, &String::from("YOU"), &String::from("SAN")), Option::from(4));
+        assert_eq!(get_hops(&map, &String::from("YOU"), &String::from("COM")), Option::None);
+        assert_eq!(get_hops(&map, &String::from("YOU"), &String::from("NOBODY")), Option::None);
+    }
+
+    #[test]
+    fn transfer_report_example() {
+        let map = map_depths(&read_puzzle_input(&format!("{}\nK)YOU\nI)SAN", EXAMPLE)).unwrap());
+        let report = transfer_report(&map);
+        assert_eq!(report.rows.iter().map(|row| row[1].as_str()).collect::<Vec<&str>>(), vec!["K", "J", "E", "D", "I"]);
+        assert_eq!(report.notes, vec!["4 orbital transfers from YOU to SAN"]);
+        assert_eq!(transfer_report(&map_depths(&read_puzzle_input(EXAMPLE).unwrap())).rows.len(), 0);
+    }
+
+    #[test]
+    fn invalid_maps() {
+        assert_eq!(read_puzzle_input("COM)B\n\n B ) C \n").unwrap().len(), 2);
+        assert_eq!(read_puzzle_input("COM)B\nB-C").unwrap_err(), String::from("Invalid orbit \"B-C\" on line 2, expected two bodies like COM)B"));
+        assert_eq!(read_puzzle_input("COM)B\nB)").unwrap_err(), String::from("Invalid orbit \"B)\" on line 2, expected two bodies like COM)B"));
+        assert_eq!(read_puzzle_input("COM)B\nB)C\nC)B").unwrap_err(), String::from("B orbits COM and C on line 3"));
+    }
+}
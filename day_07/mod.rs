@@ -0,0 +1,183 @@
+use std::error::Error;
+use crate::intcomputer::*;
+use crate::loader;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Default)]
+pub struct Puzzle {
+    mem: Vec<i64>
+}
+
+fn next_setting(set: &mut Vec<u8>, offset: u8) -> bool {
+    let mut i :usize = 0;
+    loop {
+        set[i] += 1;
+        if set[i] > 4 + offset {
+            set[i] = offset;
+            i += 1;
+            if i >= set.len() {
+                return false;
+            }
+        }
+        else {
+            break;
+        }
+    }
+    return true;
+}
+
+fn check_valid(set: &Vec<u8>) -> bool {
+    for i in 0..set.len() {
+        for y in 0..set.len() {
+            if i != y && set[i] == set[y] {
+                return false;
+            }
+        }
+    }
+    return true;
+}
+
+/**
+ * The signal an amplifier passes on is the last value it printed
+ */
+fn amp_signal(machine: &Machine) -> Result<i64, String> {
+    return machine.outputs.last().copied().ok_or(String::from("An amplifier stopped without printing a signal"));
+}
+
+fn run_amp_chain_1(mem: &Vec<i64>, settings: &Vec<u8>) -> Result<i64, String> {
+    let mut signal = 0;
+    for set_val in settings {
+        signal = amp_signal(&run_program(mem, &vec![*set_val as i64, signal]))?;
+    }
+    
+    return Ok(signal);
+}
+
+fn run_amp_chain_2(mem: &Vec<i64>, settings: &Vec<u8>) -> Result<i64, String> {
+    let mut signal = 0;
+    let mut machines: Vec<Machine> = Vec::new();
+    let mut ended = false;
+
+    for set_val in settings {
+        let machine = run_program(mem, &vec![*set_val as i64 + 5, signal]);
+        signal = amp_signal(&machine)?;
+        if !machine.can_continue() {
+            ended = true;
+            break;
+        }
+        machines.push(machine);
+    }
+
+    while !ended {
+        for machine in machines.iter_mut() {
+            machine.push_input(&vec![signal]);
+            machine.continue_program();
+            signal = amp_signal(machine)?;
+            if !machine.can_continue() {
+                ended = true;
+            }
+        }
+    }
+    
+    return Ok(signal);
+}
+
+fn find_max_signal(mem: &Vec<i64>, run_amp_chain: fn(&Vec<i64>, &Vec<u8>) -> Result<i64, String>) -> Result<i64, String> {
+    let mut settings = vec![0,0,0,0,0];
+    let mut max_val = 0;
+    'outer: loop {
+        while !check_valid(&settings){
+            if !next_setting(&mut settings, 0) {
+                break 'outer;
+            }
+        }
+
+        let signal = run_amp_chain(mem, &settings)?;
+        if signal > max_val {
+            max_val = signal;
+        }
+
+        if !next_setting(&mut settings, 0) {
+            break 'outer;
+        }
+    }
+    return Ok(max_val);
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Amplification Circuit";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_07/program.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.mem = loader::load_program(input)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return match find_max_signal(&self.mem, run_amp_chain_1) {
+            Ok(signal) => Answer::from(signal),
+            Err(e) => Answer::from(e.as_str())
+        };
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return match find_max_signal(&self.mem, run_amp_chain_2) {
+            Ok(signal) => Answer::from(signal),
+            Err(e) => Answer::from(e.as_str())
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amp_chain_examples() {
+        let prog = vec![3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0];
+        assert_eq!(run_amp_chain_1(&prog, &vec![4,3,2,1,0]), Ok(43210));
+        assert_eq!(find_max_signal(&prog, run_amp_chain_1), Ok(43210));
+
+        let prog = vec![3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,
+            1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0];
+        assert_eq!(find_max_signal(&prog, run_amp_chain_1), Ok(65210));
+    }
+
+    #[test]
+    fn feedback_loop_examples() {
+        let prog = vec![3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,
+            27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5];
+        assert_eq!(run_amp_chain_2(&prog, &vec![4,3,2,1,0]), Ok(139629729));
+        assert_eq!(find_max_signal(&prog, run_amp_chain_2), Ok(139629729));
+    }
+
+    #[test]
+    fn settings_are_permutations() {
+        let mut settings = vec![0,0,0,0,0];
+        let mut count = 0;
+        loop {
+            if check_valid(&settings) {
+                count += 1;
+            }
+            if !next_setting(&mut settings, 0) {
+                break;
+            }
+        }
+        assert_eq!(count, 120);
+    }
+
+    #[test]
+    fn silent_amplifiers() {
+        let error = Err(String::from("An amplifier stopped without printing a signal"));
+        assert_eq!(run_amp_chain_1(&vec![99], &vec![4,3,2,1,0]), error);
+        assert_eq!(run_amp_chain_2(&vec![99], &vec![4,3,2,1,0]), error);
+        assert_eq!(Puzzle { mem: vec![99] }.part1(), Answer::from("An amplifier stopped without printing a signal"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::error::Error;
+use crate::answer::Answer;
+use crate::grid::DenseGrid;
+use crate::image::{self, Picture};
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Default)]
+pub struct Puzzle {
+    img: Vec<DenseGrid<char>>
+}
+
+fn split_image(img_str: &str, size_x: usize, size_y: usize) -> Vec<DenseGrid<char>> {
+    let img_chars: Vec<char> = img_str.chars().collect();
+    return img_chars.chunks(size_x * size_y).map(|layer| DenseGrid::from_cells(size_x, layer.to_vec())).collect();
+}
+
+fn check_value(img: &Vec<DenseGrid<char>>) -> usize{
+    let mut min_zeros = std::usize::MAX;
+    let mut check_val = 0;
+    for layer in img {
+        let count_zeros = layer.cells.iter().filter(|pixel| **pixel == '0').count();
+        if count_zeros < min_zeros {
+            let count_ones = layer.cells.iter().filter(|pixel| **pixel == '1').count();
+            let count_twos = layer.cells.iter().filter(|pixel| **pixel == '2').count();
+            check_val = count_ones * count_twos;
+            min_zeros = count_zeros;
+        }
+    }
+    return check_val;
+}
+
+/**
+ * Every pixel of the image is the first one on the layers that is not transparent (2)
+ */
+fn combine_img(img: &Vec<DenseGrid<char>>) -> DenseGrid<char>{
+    let mut img_combined = match img.first() {
+        Some(layer) => DenseGrid::new(layer.width, layer.height, '2'),
+        None => return DenseGrid::new(0, 0, '2')
+    };
+    for layer in img {
+        for (i, pixel) in layer.cells.iter().enumerate() {
+            if img_combined.cells[i] == '2' {
+                img_combined.cells[i] = *pixel;
+            }
+        }
+    }
+    return img_combined;
+}
+
+fn to_pixels(img: &DenseGrid<char>) -> Vec<Vec<bool>> {
+    return img.map(|pixel| *pixel == '1').to_rows();
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Space Image Format";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_08/image.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.img = split_image(input.text()?.trim(), 25, 6);
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return Answer::from(check_value(&self.img));
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return Answer::from_image(to_pixels(&combine_img(&self.img)));
+    }
+
+    fn render(self: &Self) -> Option<Picture> {
+        return Option::from(Picture {
+            grid: combine_img(&self.img).map(|pixel| pixel.to_digit(10).unwrap_or(2) as usize),
+            palette: vec![image::BLACK, image::WHITE, (128, 128, 128)]
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value_example() {
+        let img = split_image("123456789012", 3, 2);
+        assert_eq!(img.len(), 2);
+        assert_eq!(img[1].row(0), ['7', '8', '9']);
+        assert_eq!(check_value(&img), 1);
+    }
+
+    #[test]
+    fn combine_img_example() {
+        let img = combine_img(&split_image("0222112222120000", 2, 2));
+        assert_eq!(img.to_rows(), vec![vec!['0', '1'], vec!['1', '0']]);
+        assert_eq!(to_pixels(&img), vec![vec![false, true], vec![true, false]]);
+    }
+}
@@ -0,0 +1,74 @@
+use std::error::Error;
+use crate::intcomputer::*;
+use crate::loader;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Default)]
+pub struct Puzzle {
+    mem: Vec<i64>
+}
+
+/**
+ * BOOST prints a single value, more outputs are the opcodes that failed its self check
+ */
+fn boost_output(machine: &Machine) -> Answer {
+    let result = match machine.get_result() {
+        Some(result) => result,
+        None => return Answer::from("The program is waiting for more input")
+    };
+    return match (result.code, result.outputs.as_slice()) {
+        (EndReason::EndCode, [value]) => Answer::from(*value),
+        (EndReason::EndCode, outputs) => Answer::from(format!("The self check failed for opcodes {:?}", outputs).as_str()),
+        (code, _) => Answer::from(format!("The program stopped with {:?}", code).as_str())
+    };
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Sensor Boost";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_09/program.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.mem = loader::load_program(input)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        let result = run_program(&self.mem, &vec![1]);
+        return boost_output(&result);
+    }
+
+    fn part2(self: &Self) -> Answer {
+        let result_2 = run_program(&self.mem, &vec![2]);
+        return boost_output(&result_2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_base_examples() {
+        let quine = vec![109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99];
+        assert_eq!(run_program(&quine, &vec![]).get_result().unwrap().outputs, quine);
+
+        let large = run_program(&vec![1102,34915192,34915192,7,4,7,99,0], &vec![]);
+        assert_eq!(large.outputs[0].to_string().len(), 16);
+        assert_eq!(run_program(&vec![104,1125899906842624,99], &vec![]).outputs, vec![1125899906842624]);
+    }
+
+    #[test]
+    fn boost_failures() {
+        assert_eq!(boost_output(&run_program(&vec![104,42,99], &vec![])), Answer::Number(42));
+        assert_eq!(boost_output(&run_program(&vec![104,203,104,42,99], &vec![])), Answer::from("The self check failed for opcodes [203, 42]"));
+        assert_eq!(boost_output(&run_program(&vec![104,42,42], &vec![])), Answer::from("The program stopped with CodeUnknown"));
+        assert_eq!(boost_output(&run_program(&vec![3,0,99], &vec![])), Answer::from("The program is waiting for more input"));
+    }
+}
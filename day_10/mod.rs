@@ -0,0 +1,212 @@
+extern crate ordered_float;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use ordered_float::OrderedFloat;
+use crate::answer::Answer;
+use crate::grid::Point;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Debug,Copy,Clone)]
+struct Astroid {
+    distance: f32,
+    pos: Point<i32>,
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    astroids: Vec<Point<i32>>
+}
+
+fn read_puzzle_input(input: &str) -> Vec<Point<i32>> {
+    let mut astroids : Vec<Point<i32>> = Vec::new();
+
+    for (y, uline) in input.lines().enumerate() {
+        for (x, ch) in uline.chars().enumerate() {
+            if ch == '#' {
+                astroids.push(Point::from(x as i32, y as i32));
+            }
+        }
+    }
+
+    return astroids;
+}
+
+fn calc_angle(station: &Point<i32>, astroid: &Point<i32>) -> f32{
+    return (station.y  as f32 - astroid.y  as f32).atan2(station.x  as f32 - astroid.x  as f32);
+}
+
+fn calc_distance(station: &Point<i32>, astroid: &Point<i32>) -> f32{
+    let x = station.x  as f32 - astroid.x  as f32;
+    let y = station.y  as f32 - astroid.y  as f32;
+    return (x*x + y*y).sqrt();
+}
+
+fn get_inline(station: &Point<i32>, astroids: &Vec<Point<i32>>) -> Vec<Point<i32>> {
+    let mut dist_map: HashMap<OrderedFloat<f32>, Astroid> = HashMap::new();
+    for astroid in astroids {
+        if astroid == station {
+            continue;
+        }
+
+        let angle = calc_angle(&station, &astroid);
+        let dist = calc_distance(station, &astroid);
+        let astr = dist_map.get(&OrderedFloat::from(angle));
+
+        if astr.is_none() || (astr.is_some() && astr.unwrap().distance > dist) {
+            dist_map.insert(OrderedFloat::from(angle), Astroid {
+                distance: dist,
+                pos: astroid.clone()
+            });
+        }
+    }
+
+    let mut positions = Vec::new();
+    for (_a, astroid) in dist_map.into_iter(){
+        positions.push(astroid.pos);
+    }
+
+    return positions;
+}
+
+#[allow(dead_code)]
+fn print_debug(base: &Point<i32>, eliminated: &Vec<Point<i32>>){
+    println!("Base at {},{}",base.x, base.y);
+
+    for i in 0..eliminated.len() {
+        println!("{} -> {},{}",i, eliminated[i].x, eliminated[i].y);
+    }
+}
+
+fn relative_rotation(mut angle: f32, rotation: f32) -> f32{
+    angle -= std::f32::consts::PI / 2f32;
+    angle -= rotation;
+    while angle < 0f32 {
+        angle += std::f32::consts::PI * 2f32;
+    }
+    return angle;
+}
+
+fn vaporization_procedure(station: &Point<i32>, astroids_in: &Vec<Point<i32>>) -> Vec<Point<i32>>{
+    let mut astroids: HashSet<Point<i32>> = HashSet::new();
+    for astroid in astroids_in {
+        astroids.insert(astroid.clone());
+    }
+    
+    let mut vaporized = Vec::new();
+    let mut station_rot = 0.0f32;
+
+    while !astroids.is_empty() {
+        let mut inline = get_inline(&station, &astroids.clone().into_iter().collect());
+        inline.sort_by(|a, b| relative_rotation(calc_angle(&station, a),station_rot).partial_cmp(&relative_rotation(calc_angle(&station, b), station_rot)).unwrap());
+        
+        if inline.len() == 0 {
+            break;
+        }
+
+        for vap_astr in &inline {
+            vaporized.push(vap_astr.clone());
+            astroids.remove(&vap_astr);
+        }
+        station_rot =  calc_angle(&station, &inline[inline.len() - 1]);
+    }
+    return vaporized;
+}
+
+fn find_station(astroids: &Vec<Point<i32>>) -> (usize, Option<Point<i32>>) {
+    let mut max_astroids = 0;
+    let mut best_place: Option<Point<i32>> = Option::None;
+    for astroid in astroids {
+        let count = get_inline(&astroid, astroids).len();
+        if count > max_astroids {
+            max_astroids = count;
+            best_place = Option::from(astroid.clone());
+        }
+    }
+
+    return (max_astroids, best_place);
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Monitoring Station";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_10/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.astroids = read_puzzle_input(input.text()?);
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return Answer::from(find_station(&self.astroids).0);
+    }
+
+    fn part2(self: &Self) -> Answer {
+        let best_place = find_station(&self.astroids).1;
+        if best_place.is_some() {
+            let order = vaporization_procedure(&best_place.unwrap(), &self.astroids);
+            if order.len() >= 200 {
+                return Answer::from(order[199].x * 100 + order[199].y);
+            }
+        }
+        return Answer::from("Less than 200 astroids vaporized");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = ".#..#\n.....\n#####\n....#\n...##";
+    const LARGE: &str = ".#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##";
+
+    #[test]
+    fn get_inline_small_example() {
+        let astroids = read_puzzle_input(SMALL);
+        assert_eq!(get_inline(&Point::from(3, 4), &astroids).len(), 8);
+        assert_eq!(get_inline(&Point::from(1, 0), &astroids).len(), 7);
+        assert_eq!(get_inline(&Point::from(4, 2), &astroids).len(), 5);
+    }
+
+    #[test]
+    fn find_station_examples() {
+        assert_eq!(find_station(&read_puzzle_input(SMALL)), (8, Option::from(Point::from(3, 4))));
+        assert_eq!(find_station(&read_puzzle_input(LARGE)), (210, Option::from(Point::from(11, 13))));
+    }
+
+    #[test]
+    fn vaporization_example() {
+        let astroids = read_puzzle_input(LARGE);
+        let order = vaporization_procedure(&Point::from(11, 13), &astroids);
+        assert_eq!(order.len(), astroids.len() - 1);
+        assert_eq!(order[0], Point::from(11, 12));
+        assert_eq!(order[1], Point::from(12, 1));
+        assert_eq!(order[199], Point::from(8, 2));
+        assert_eq!(order[298], Point::from(11, 1));
+    }
+}
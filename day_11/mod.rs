@@ -0,0 +1,184 @@
+use std::error::Error;
+use crate::intasync::*;
+use crate::loader;
+use crate::answer::Answer;
+use crate::grid::{Direction, DenseGrid, Point, SparseGrid};
+use crate::image::{self, Picture};
+use crate::recorder::Recorder;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+enum Paint {
+    BLACK,
+    WHITE,
+    NONE
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    mem: Vec<i64>
+}
+
+impl Paint {
+    fn from_intcode(code: i64) -> Self{
+        return match code {
+            0 => Self::BLACK,
+            1 => Self::WHITE,
+            _ => Self::NONE
+        }
+    }
+
+    fn to_intcode(self: &Self) -> Option<i64>{
+        return match self {
+            Self::BLACK => Option::from(0),
+            Self::WHITE => Option::from(1),
+            _ => Option::None
+        }
+    }
+}
+
+fn to_pixels(img: &DenseGrid<Paint>) -> Vec<Vec<bool>> {
+    return img.map(|paint| *paint == Paint::WHITE).to_rows();
+}
+
+/**
+ * Frame of the hull with the robot, 0 is unpainted, 1 black, 2 white and 3 the robot
+ */
+fn snapshot(img: &SparseGrid<Paint>, robot: Point<i64>) -> SparseGrid<usize> {
+    let mut frame = SparseGrid::new();
+    for (pos, paint) in &img.cells {
+        frame.insert(*pos, paint.to_intcode().map_or(0, |code| code as usize + 1));
+    }
+    frame.insert(robot, 3);
+    return frame;
+}
+
+async fn paint_controller(input: Sender, mut output: Receiver, background: Paint, mut recorder: Option<Recorder>) -> (SparseGrid<Paint>, Option<Recorder>){
+    let mut img: SparseGrid<Paint> = SparseGrid::new();
+    let mut pos = Point::from(0, 0);
+    let mut dir = Direction::UP;
+
+    let default_color = background.to_intcode().unwrap_or(0);
+
+    loop {
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.step(|| snapshot(&img, pos));
+        }
+
+        let paint = img.get(&pos);
+        let mut paint_code = Option::None;
+        if paint.is_some() {
+            paint_code = paint.unwrap().to_intcode();
+        }
+        input.send(paint_code.unwrap_or(default_color));
+
+        let color = output.next().await;
+        let turn = output.next().await;
+        if color.is_none() || turn.is_none() {
+            break;
+        }
+
+        img.insert(pos, Paint::from_intcode(color.unwrap()));
+        match turn.unwrap() {
+            0 => dir = dir.turn_left(),
+            1 => dir = dir.turn_right(),
+            _ => {}
+        }
+        pos = dir.move_point(pos, 1);
+    }
+
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.finish(|| snapshot(&img, pos));
+    }
+    return (img, recorder);
+}
+
+fn run_painter(mem: &Vec<i64>, background: Paint, recorder: Option<Recorder>) -> (SparseGrid<Paint>, Option<Recorder>){
+    let (machine, input, output) = AsyncMachine::make(mem);
+    let mut executor = Executor::new();
+    executor.spawn(machine.run());
+    return executor.block_on(paint_controller(input, output, background, recorder)).unwrap();
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Space Police";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_11/program.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.mem = loader::load_program(input)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        let img = run_painter(&self.mem, Paint::BLACK, Option::None).0;
+        return Answer::from(img.len());
+    }
+
+    fn part2(self: &Self) -> Answer {
+        let img2 = run_painter(&self.mem, Paint::WHITE, Option::None).0;
+        let img_arr = img2.to_dense(Paint::BLACK);
+        return Answer::from_image(to_pixels(&img_arr));
+    }
+
+    fn render(self: &Self) -> Option<Picture> {
+        let img = run_painter(&self.mem, Paint::WHITE, Option::None).0.to_dense(Paint::NONE);
+        return Option::from(Picture {
+            grid: img.map(|paint| paint.to_intcode().unwrap_or(2) as usize),
+            palette: vec![image::BLACK, image::WHITE, (64, 64, 64)]
+        });
+    }
+
+    fn record(self: &Self, mut recorder: Recorder) -> Option<Recorder> {
+        recorder.palette = vec![(32, 32, 32), image::BLACK, image::WHITE, (255, 64, 64)];
+        return run_painter(&self.mem, Paint::WHITE, Option::from(recorder)).1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Program that ignores the camera and answers with the given color and turn for every input
+     */
+    fn scripted_robot(moves: &[(i64, i64)]) -> Vec<i64> {
+        let mut prog = Vec::new();
+        for (color, turn) in moves {
+            prog.extend(vec![3, 1000, 104, *color, 104, *turn]);
+        }
+        prog.push(99);
+        return prog;
+    }
+
+    #[test]
+    fn painted_panels_example() {
+        // Moves of the puzzle example, the robot ends at 0,-1 facing left
+        let prog = scripted_robot(&[(1, 0), (0, 0), (1, 0), (1, 0), (0, 1), (1, 0), (1, 0)]);
+        let panels = run_painter(&prog, Paint::BLACK, Option::None).0;
+        assert_eq!(panels.len(), 6);
+        assert_eq!(panels.get(&Point::from(0, 0)), Option::from(&Paint::BLACK));
+        assert_eq!(to_pixels(&panels.to_dense(Paint::BLACK)), vec![
+            vec![false, false, true],
+            vec![false, false, true],
+            vec![true, true, false]
+        ]);
+    }
+
+    #[test]
+    fn camera_reads_background() {
+        // Paints every panel with the color the camera sees and turns right, so a white
+        // background stays white and the robot walks in a square
+        let step = vec![3, 1000, 4, 1000, 104, 1];
+        let prog: Vec<i64> = step.iter().cycle().take(step.len() * 5).copied().chain(vec![99]).collect();
+        let white = run_painter(&prog, Paint::WHITE, Option::None).0;
+        assert_eq!(white.len(), 4);
+        assert_eq!(white.count(|paint| *paint == Paint::WHITE), 4);
+        assert_eq!(run_painter(&prog, Paint::BLACK, Option::None).0.count(|paint| *paint == Paint::BLACK), 4);
+    }
+}
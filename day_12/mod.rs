@@ -0,0 +1,326 @@
+extern crate regex;
+extern crate num;
+
+use self::regex::Regex;
+use std::collections::HashSet;
+use std::error::Error;
+use crate::answer::Answer;
+use crate::grid::{Point, SparseGrid};
+use crate::image;
+use crate::recorder::Recorder;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+struct Vec3D {
+    x: i16,
+    y: i16,
+    z: i16,
+}
+
+impl Vec3D {
+    fn zero() -> Self{
+        return Vec3D {
+            x: 0,
+            y: 0,
+            z: 0
+        }
+    }
+}
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+struct Moon {
+    velocity: Vec3D,
+    pos: Vec3D,
+}
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+struct Moon1D {
+    velocity: i16,
+    pos: i16,
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    moon_pos: Vec<Vec3D>
+}
+
+impl Moon {
+    fn energy(self: &Self) -> i32{
+        let kin = self.velocity.x.abs() + self.velocity.y.abs() + self.velocity.z.abs();
+        let pot = self.pos.x.abs() + self.pos.y.abs() + self.pos.z.abs();
+        return kin as i32 * pot as i32;
+    }
+    
+}
+
+lazy_static! {
+    static ref CABELE_REGEX : Regex = Regex::new(r"^<x=(?P<x>-?\d+),\s*y=(?P<y>-?\d+),\s*z=(?P<z>-?\d+)>$").unwrap();
+}
+
+/**
+ * One moon position like "<x=-1, y=0, z=2>" per line, blank lines are skipped
+ */
+fn read_puzzle_input(input: &str) -> Result<Vec<Vec3D>, String> {
+    let mut moon_pos : Vec<Vec3D> = Vec::new();
+
+
+    for (i, uline) in input.lines().enumerate() {
+        if uline.trim().is_empty() {
+            continue;
+        }
+        let caps = CABELE_REGEX.captures(uline.trim())
+            .ok_or(format!("Invalid moon \"{}\" on line {}, expected a position like <x=-1, y=0, z=2>", uline.trim(), i + 1))?;
+        let coord = |name: &str| caps[name].parse::<i16>()
+            .map_err(|_e| format!("Coordinate {}={} of the moon on line {} is too large", name, &caps[name], i + 1));
+        moon_pos.push(Vec3D {
+            x: coord("x")?,
+            y: coord("y")?,
+            z: coord("z")?,
+        })
+            
+    }
+
+    if moon_pos.is_empty() {
+        return Err(String::from("The input contains no moons"));
+    }
+    return Ok(moon_pos);
+}
+
+fn step_position(moons: &mut Vec<Moon>){
+    let temp = moons.clone();
+    for moon in moons {
+        for temp_moon in &temp {
+            if temp_moon.pos.x < moon.pos.x {
+                moon.velocity.x -= 1;
+            }
+            else if temp_moon.pos.x > moon.pos.x {
+                moon.velocity.x += 1;
+            }
+
+            if temp_moon.pos.y < moon.pos.y {
+                moon.velocity.y -= 1;
+            }
+            else if temp_moon.pos.y > moon.pos.y {
+                moon.velocity.y += 1;
+            }
+
+            if temp_moon.pos.z < moon.pos.z {
+                moon.velocity.z -= 1;
+            }
+            else if temp_moon.pos.z > moon.pos.z {
+                moon.velocity.z += 1;
+            }
+        }
+
+        moon.pos.x += moon.velocity.x;
+        moon.pos.y += moon.velocity.y;
+        moon.pos.z += moon.velocity.z;
+    }
+}
+
+fn step_position_1d(moons: &mut Vec<Moon1D>){
+    let temp = moons.clone();
+    for moon in moons {
+        for temp_moon in &temp {
+            if temp_moon.pos < moon.pos {
+                moon.velocity -= 1;
+            }
+            else if temp_moon.pos > moon.pos {
+                moon.velocity += 1;
+            }
+        }
+
+        moon.pos += moon.velocity;
+    }
+}
+
+/*
+ * The function was originally implemented to iterate until it found a previous state in a hash set see
+ * commit 611d289c146871046866c28e783bf2e111b69e0f the original is also implemented as find_steps_1d_old below (dead_code).
+ * The accellerated version was inspired by: https://www.reddit.com/r/adventofcode/comments/e9nqpq/day_12_part_2_2x_faster_solution/
+ * It makes the code approximately 2-times faster because the return path it exactly the inverse and begins when the velocity is 0.
+ * Therefor it is enough to count the steps to the first 0 velocity (after start) and multiply that by 2.
+ * In this case this will probably be more than 2x faster because the whole hashset seach can be removed.
+ */
+fn find_steps_1d(moons: &mut Vec<Moon1D>) -> u64{
+    let mut steps = 0;
+    let mut done = false;
+    while !done {
+        step_position_1d(moons);
+        steps += 1;
+        done = true;
+        for moon in moons.iter(){
+            if moon.velocity != 0 {
+                done = false;
+                break;
+            }
+        }
+    }
+    return steps as u64 * 2;
+}
+
+/*
+ * This function was replaced by find_steps_1d since it is faster. But this is the original function based on 
+ * the solution before I read the reddit post.
+ */
+#[allow(dead_code)]
+fn find_steps_1d_old(moons: &mut Vec<Moon1D>) -> u64{
+    let mut states: HashSet<Vec<Moon1D>> = HashSet::new();
+    while states.get(moons).is_none() {
+        states.insert(moons.clone());
+        step_position_1d(moons);
+    }
+    return states.len() as u64;
+}
+
+
+/**
+ * Frame with the moons projected on the x-y plane, moon i has color i + 1
+ */
+fn snapshot(moons: &Vec<Moon>) -> SparseGrid<usize> {
+    let mut frame = SparseGrid::new();
+    for (i, moon) in moons.iter().enumerate() {
+        frame.insert(Point::from(moon.pos.x as i64, moon.pos.y as i64), i + 1);
+    }
+    return frame;
+}
+
+fn simulate(moon_pos: &Vec<Vec3D>, steps: u32, mut recorder: Option<&mut Recorder>) -> Vec<Moon> {
+    let mut moons: Vec<Moon> = Vec::new(); 
+    for pos in moon_pos {
+        moons.push(Moon {
+            pos: *pos,
+            velocity: Vec3D::zero()
+        });
+    }
+    for _i in 0..steps {
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.step(|| snapshot(&moons));
+        }
+        step_position(&mut moons);
+    }
+
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.finish(|| snapshot(&moons));
+    }
+    return moons;
+}
+
+fn simulate_energy(moon_pos: &Vec<Vec3D>) -> i32 {
+    let moons = simulate(moon_pos, 1000, Option::None);
+
+    let mut energy = 0;
+    for moon in &moons {
+        energy += moon.energy();
+    }
+    return energy;
+}
+
+fn find_repetition(moon_pos: &Vec<Vec3D>) -> u64 {
+    let mut moons1d: Vec<Vec<Moon1D>> = Vec::new();
+    
+    moons1d.push(Vec::new());
+    moons1d.push(Vec::new());
+    moons1d.push(Vec::new());
+
+    for pos in moon_pos {
+        moons1d[0].push(Moon1D {
+            pos: pos.x,
+            velocity: 0
+        });
+        moons1d[1].push(Moon1D {
+            pos: pos.y,
+            velocity: 0
+        });
+        moons1d[2].push(Moon1D {
+            pos: pos.z,
+            velocity: 0
+        });
+    }
+
+    let step_1 = find_steps_1d(&mut moons1d[0]);
+    let step_2 = find_steps_1d(&mut moons1d[1]);
+    let step_3 = find_steps_1d(&mut moons1d[2]);
+    return num::integer::lcm(step_1, num::integer::lcm(step_2, step_3));
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "The N-Body Problem";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_12/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.moon_pos = read_puzzle_input(input.text()?)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return Answer::from(simulate_energy(&self.moon_pos));
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return Answer::from(find_repetition(&self.moon_pos));
+    }
+
+    fn record(self: &Self, mut recorder: Recorder) -> Option<Recorder> {
+        recorder.palette = vec![image::BLACK, (255, 64, 64), (64, 255, 64), (64, 128, 255), (255, 255, 64)];
+        simulate(&self.moon_pos, 1000, Option::from(&mut recorder));
+        return Option::from(recorder);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>";
+    const EXAMPLE_2: &str = "<x=-8, y=-10, z=0>\n<x=5, y=5, z=10>\n<x=2, y=-7, z=3>\n<x=9, y=-8, z=-3>";
+
+    fn energy_after(input: &str, steps: u32) -> i32 {
+        return simulate(&read_puzzle_input(input).unwrap(), steps, Option::None).iter().map(|moon| moon.energy()).sum();
+    }
+
+    #[test]
+    fn energy_examples() {
+        assert_eq!(energy_after(EXAMPLE_1, 10), 179);
+        assert_eq!(energy_after(EXAMPLE_2, 100), 1940);
+    }
+
+    #[test]
+    fn record_moons() {
+        let mut recorder = Recorder::new(5);
+        simulate(&read_puzzle_input(EXAMPLE_1).unwrap(), 10, Option::from(&mut recorder));
+        assert_eq!(recorder.frames.len(), 3);
+        assert_eq!(recorder.frames[0].get(&Point::from(-1, 0)), Option::from(&1));
+    }
+
+    #[test]
+    fn find_steps_1d_example() {
+        let mut moons: Vec<Moon1D> = read_puzzle_input(EXAMPLE_1).unwrap().iter().map(|pos| Moon1D {
+            pos: pos.x,
+            velocity: 0
+        }).collect();
+        let mut moons_old = moons.clone();
+        assert_eq!(find_steps_1d(&mut moons), 18);
+        assert_eq!(find_steps_1d_old(&mut moons_old), 18);
+    }
+
+    #[test]
+    fn find_repetition_examples() {
+        assert_eq!(find_repetition(&read_puzzle_input(EXAMPLE_1).unwrap()), 2772);
+        assert_eq!(find_repetition(&read_puzzle_input(EXAMPLE_2).unwrap()), 4686774924);
+    }
+
+    #[test]
+    fn invalid_positions() {
+        assert_eq!(read_puzzle_input("\n<x=-1, y=0, z=2>\n\n").unwrap(), vec![Vec3D { x: -1, y: 0, z: 2 }]);
+        assert_eq!(read_puzzle_input("\n\n").unwrap_err(), "The input contains no moons");
+        assert_eq!(read_puzzle_input("<x=-1, y=0, z=2>\n<x=1, y=, z=2>").unwrap_err(), "Invalid moon \"<x=1, y=, z=2>\" on line 2, expected a position like <x=-1, y=0, z=2>");
+        assert_eq!(read_puzzle_input("<x=1, y=40000, z=2>").unwrap_err(), "Coordinate y=40000 of the moon on line 1 is too large");
+    }
+}
@@ -0,0 +1,203 @@
+use std::error::Error;
+use crate::intcomputer::*;
+use crate::intasync::*;
+use crate::loader;
+use crate::answer::Answer;
+use crate::grid::{Point, SparseGrid};
+use crate::image::{self, Color, Picture};
+use crate::recorder::Recorder;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+enum Tile {
+    EMPTY,
+    WALL,
+    BLOCK,
+    HPADDLE,
+    BALL
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    mem: Vec<i64>
+}
+
+impl Tile {
+    fn from_intcode(code: i64) -> Self{
+        return match code {
+            0 => Self::EMPTY,
+            1 => Self::WALL,
+            2 => Self::BLOCK,
+            3 => Self::HPADDLE,
+            4 => Self::BALL,
+            _ => Self::EMPTY
+        }
+    }
+
+    fn to_intcode(self: &Self) -> Option<i64>{
+        return match self {
+            Self::EMPTY => Option::from(0),
+            Self::WALL => Option::from(1),
+            Self::BLOCK => Option::from(2),
+            Self::HPADDLE => Option::from(3),
+            Self::BALL => Option::from(4),
+        }
+    }
+}
+
+fn run_game_1(mem: &Vec<i64>) -> SparseGrid<Tile>{
+    let mut field: SparseGrid<Tile> = SparseGrid::new();
+
+    let mut machine = Machine::make(mem, &vec![]);
+    machine.continue_program();
+
+    let mut count = 0;
+    while count < machine.outputs.len() {
+        field.insert(Point::from(machine.outputs[count], machine.outputs[count + 1]), Tile::from_intcode(machine.outputs[count + 2]));
+        count += 3;
+    }
+    return field;
+}
+
+const PALETTE: [Color; 5] = [image::BLACK, (128, 128, 128), (64, 128, 255), image::WHITE, (255, 64, 64)];
+
+fn snapshot(field: &SparseGrid<Tile>) -> SparseGrid<usize> {
+    let mut frame = SparseGrid::new();
+    for (pos, tile) in &field.cells {
+        frame.insert(*pos, tile.to_intcode().unwrap() as usize);
+    }
+    return frame;
+}
+
+async fn game_controller(input: Sender, mut output: Receiver, mut recorder: Option<Recorder>) -> (u16, Option<Recorder>){
+    let mut field: SparseGrid<Tile> = SparseGrid::new();
+    let mut score = 0;
+    let mut tile: Vec<i64> = Vec::new();
+
+    loop {
+        let requested = input.request().await;
+
+        while let Some(val) = output.try_recv() {
+            tile.push(val);
+            if tile.len() < 3 {
+                continue;
+            }
+
+            let pos = Point::from(tile[0], tile[1]);
+            if pos.x == -1 && pos.y == 0 {
+                score = tile[2] as u16;
+            }
+            else {
+                field.insert(pos, Tile::from_intcode(tile[2]));
+            }
+            tile.clear();
+        }
+
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.step(|| snapshot(&field));
+        }
+        if !requested {
+            break;
+        }
+
+        let ball = field.find(|tile| *tile == Tile::BALL);
+        let paddle = field.find(|tile| *tile == Tile::HPADDLE);
+        let mut in_key = 0;
+        if ball.is_some() && paddle.is_some() {
+            if ball.unwrap().x < paddle.unwrap().x {
+                in_key = -1;
+            }
+            else if ball.unwrap().x > paddle.unwrap().x {
+                in_key = 1;
+            }
+        }
+        input.send(in_key);
+    }
+
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.finish(|| snapshot(&field));
+    }
+    return (score, recorder);
+}
+
+fn run_game_2(mem: &Vec<i64>, recorder: Option<Recorder>) -> (u16, Option<Recorder>){
+    let (mut machine, input, output) = AsyncMachine::make(mem);
+    machine.machine.memory[0] = 2;
+
+    let mut executor = Executor::new();
+    executor.spawn(machine.run());
+    return executor.block_on(game_controller(input, output, recorder)).unwrap();
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Care Package";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_13/program.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.mem = loader::load_program(input)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        let field = run_game_1(&self.mem);
+        return Answer::from(field.count(|tile| *tile == Tile::BLOCK));
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return Answer::from(run_game_2(&self.mem, Option::None).0);
+    }
+
+    fn render(self: &Self) -> Option<Picture> {
+        let field = run_game_1(&self.mem).to_dense(Tile::EMPTY);
+        return Option::from(Picture {
+            grid: field.map(|tile| tile.to_intcode().unwrap() as usize),
+            palette: PALETTE.to_vec()
+        });
+    }
+
+    fn record(self: &Self, mut recorder: Recorder) -> Option<Recorder> {
+        recorder.palette = PALETTE.to_vec();
+        return run_game_2(&self.mem, Option::from(recorder)).1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(tiles: &[(i64, i64, i64)]) -> Vec<i64> {
+        return tiles.iter().flat_map(|(x, y, tile)| vec![104, *x, 104, *y, 104, *tile]).collect();
+    }
+
+    #[test]
+    fn count_blocks() {
+        // The last tile clears the block at 1,0 again
+        let mut prog = tiles(&[(0, 0, 2), (1, 0, 2), (2, 0, 1), (0, 1, 3), (1, 1, 4), (1, 0, 0)]);
+        prog.push(99);
+        let field = run_game_1(&prog);
+        assert_eq!(field.count(|tile| *tile == Tile::BLOCK), 1);
+        assert_eq!(field.get(&Point::from(1, 1)), Option::from(&Tile::BALL));
+        assert_eq!(field.len(), 5);
+    }
+
+    #[test]
+    fn joystick_follows_ball() {
+        // mem[0] is the coin slot, free play turns the first add into a harmless multiply.
+        // Draws the ball right of the paddle, reads the joystick and shows it + 10 as score.
+        let mut prog = vec![1, 200, 200, 200];
+        prog.extend(tiles(&[(5, 1, 4), (3, 2, 3)]));
+        prog.extend(vec![3, 100, 1001, 100, 10, 100, 104, -1, 104, 0, 4, 100, 99]);
+        assert_eq!(run_game_2(&prog, Option::None).0, 11);
+
+        let mut prog = vec![1, 200, 200, 200];
+        prog.extend(tiles(&[(1, 1, 4), (3, 2, 3)]));
+        prog.extend(vec![3, 100, 1001, 100, 10, 100, 104, -1, 104, 0, 4, 100, 99]);
+        assert_eq!(run_game_2(&prog, Option::None).0, 9);
+    }
+}
@@ -0,0 +1,213 @@
+extern crate regex;
+
+use self::regex::Regex;
+use std::collections::HashMap;
+use std::error::Error;
+use crate::answer::Answer;
+use crate::day::Day;
+use crate::input::Input;
+
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+struct Reaction {
+    input: Vec<(String, u32)>,
+    output: (String, u32)
+}
+
+#[derive(Default)]
+pub struct Puzzle {
+    reactions: Vec<Reaction>
+}
+
+
+lazy_static! {
+    static ref REACTION_REGEX : Regex = Regex::new(r"^(\d+)\s*(\w+)$").unwrap();
+}
+
+fn parse_ingridient(inp: &str, line: usize) -> Result<(String, u32), String> {
+    let error = || format!("Invalid chemical \"{}\" on line {}, expected an amount and a name like 7 A", inp.trim(), line);
+    let caps = REACTION_REGEX.captures(inp.trim()).ok_or_else(error)?;
+    let amount = caps[1].parse().map_err(|_e| error())?;
+    return Ok((String::from(&caps[2]), amount));
+}
+
+/**
+ * One reaction like "7 A, 1 B => 1 C" per line, blank lines are skipped. Every chemical
+ * besides ORE has to be produced by a reaction and one of them has to produce FUEL.
+ */
+fn read_puzzle_input(input: &str) -> Result<Vec<Reaction>, String> {
+    let mut reactions : Vec<Reaction> = Vec::new();
+    let mut needed: Vec<(String, usize)> = vec![(String::from("FUEL"), 0)];
+
+    for (i, uline) in input.lines().enumerate() {
+        if uline.trim().is_empty() {
+            continue;
+        }
+        let parts: Vec<&str> = uline.split("=>").collect();
+        if parts.len() != 2 {
+            return Err(format!("Invalid reaction \"{}\" on line {}, expected inputs and an output like 7 A, 1 B => 1 C", uline.trim(), i + 1));
+        }
+        
+        let unp_ingridients = parts[0];
+        let mut ingridients: Vec<(String, u32)> = Vec::new();
+        for ingridient in unp_ingridients.split(",") {
+            let parsed = parse_ingridient(ingridient, i + 1)?;
+            needed.push((parsed.0.clone(), i + 1));
+            ingridients.push(parsed);
+        }
+
+        reactions.push(Reaction {
+            input: ingridients,
+            output: parse_ingridient(parts[1], i + 1)?
+        });
+            
+    }
+
+    for (chemical, line) in needed {
+        if chemical != "ORE" && !reactions.iter().any(|x| x.output.0 == chemical) {
+            return Err(match line {
+                0 => String::from("No reaction produces FUEL"),
+                _ => format!("No reaction produces {} needed on line {}", chemical, line)
+            });
+        }
+    }
+
+    return Ok(reactions);
+}
+
+fn find_reaction(reactions: &Vec<Reaction>, output: &str) -> usize {
+    return reactions.iter().position(|x| x.output.0 == output).unwrap();
+}
+
+fn get_ore(reactions: &Vec<Reaction>, output: &str, mut amount: u32, overflow: &mut HashMap<String, u32>) -> u32{
+
+    if *overflow.get(output).unwrap_or(&0) >= amount {
+        overflow.insert(output.to_string(), overflow.get(output).unwrap() - amount);
+        return 0;
+    }
+
+    amount -= *overflow.get(output).unwrap_or(&0);
+
+    let index = find_reaction(reactions, output);
+    let reaction = &reactions[index];
+
+    let mut mult = 1;
+    while mult * reaction.output.1 < amount {
+        mult += 1;
+    }
+
+    let mut ore = 0;
+    for ingr in &reaction.input {
+
+        if ingr.0 == "ORE" {
+            ore += ingr.1 * mult;
+            continue;
+        }
+
+        let result = get_ore(&reactions, &ingr.0, ingr.1 * mult, overflow);
+        ore+= result;
+    }
+    
+    overflow.insert(output.to_string(), mult * reaction.output.1 - amount);
+    
+    return ore;
+}
+
+fn get_ore_once(reactions: &Vec<Reaction>, output: &str) -> f64{
+    let index = find_reaction(reactions, output);
+    let reaction = &reactions[index];
+
+    let mut ore = 0.0;
+    for ingr in &reaction.input {
+
+        if ingr.0 == "ORE" {
+            ore += ingr.1 as f64;
+            continue;
+        }
+
+        ore += get_ore_once(&reactions, &ingr.0) * (ingr.1 as f64);
+    }
+    
+    return ore / (reaction.output.1 as f64);
+}
+
+impl Day for Puzzle {
+    fn title(self: &Self) -> &'static str {
+        return "Space Stoichiometry";
+    }
+
+    fn input_path(self: &Self) -> &'static str {
+        return "day_14/input.txt";
+    }
+
+    fn parse(self: &mut Self, input: &Input) -> Result<(), Box<dyn Error>> {
+        self.reactions = read_puzzle_input(input.text()?)?;
+        return Ok(());
+    }
+
+    fn part1(self: &Self) -> Answer {
+        return Answer::from(get_ore(&self.reactions,"FUEL",1,&mut HashMap::new()));
+    }
+
+    fn part2(self: &Self) -> Answer {
+        return Answer::from((1000000000000.0 / get_ore_once(&self.reactions,"FUEL")).floor() as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL";
+
+    const EXAMPLE_2: &str = "9 ORE => 2 A
+8 ORE => 3 B
+7 ORE => 5 C
+3 A, 4 B => 1 AB
+5 B, 7 C => 1 BC
+4 C, 1 A => 1 CA
+2 AB, 3 BC, 4 CA => 1 FUEL";
+
+    const EXAMPLE_3: &str = "157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT";
+
+    #[test]
+    fn parse_ingridient_example() {
+        assert_eq!(parse_ingridient(" 7 A", 1), Ok((String::from("A"), 7)));
+        assert_eq!(read_puzzle_input(EXAMPLE_1).unwrap()[2].input, vec![(String::from("A"), 7), (String::from("B"), 1)]);
+    }
+
+    #[test]
+    fn get_ore_examples() {
+        for (input, ore) in [(EXAMPLE_1, 31), (EXAMPLE_2, 165), (EXAMPLE_3, 13312)] {
+            assert_eq!(get_ore(&read_puzzle_input(input).unwrap(), "FUEL", 1, &mut HashMap::new()), ore);
+        }
+    }
+
+    #[test]
+    fn max_fuel_example() {
+        let puzzle = Puzzle { reactions: read_puzzle_input(EXAMPLE_3).unwrap() };
+        assert_eq!(puzzle.part2(), Answer::from(82892753));
+    }
+
+    #[test]
+    fn invalid_reactions() {
+        assert_eq!(read_puzzle_input("\n10 ORE => 1 FUEL\n\n").unwrap().len(), 1);
+        assert_eq!(read_puzzle_input("x").unwrap_err(), "Invalid reaction \"x\" on line 1, expected inputs and an output like 7 A, 1 B => 1 C");
+        assert_eq!(read_puzzle_input("10 ORE => 1 A\nA, 1 ORE => 1 FUEL").unwrap_err(), "Invalid chemical \"A\" on line 2, expected an amount and a name like 7 A");
+        assert_eq!(read_puzzle_input("10 ORE => 1 A\n").unwrap_err(), "No reaction produces FUEL");
+        assert_eq!(read_puzzle_input("10 ORE => 1 A\n1 A, 2 B => 1 FUEL").unwrap_err(), "No reaction produces B needed on line 2");
+        assert_eq!(read_puzzle_input("\n\n").unwrap_err(), "No reaction produces FUEL");
+    }
+}
@@ -1,12 +1,5 @@
 #!/bin/bash
 
-cargo build --release
+cargo build --release --quiet
 
-TARGET=./target/release
-
-for i in {1..25}
-do
-    if [ -f "$TARGET/day$i" ]; then
-        $TARGET/day$i
-    fi
-done
\ No newline at end of file
+./target/release/aoc run all "$@"